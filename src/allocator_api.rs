@@ -0,0 +1,128 @@
+//! Support of custom allocators (nightly only), with a [`NonEmptyVec`]
+//! generic over its allocator.
+//!
+//! The core methods (`len`, `first`, `push`, etc.) are available whatever
+//! the allocator.
+
+use {
+    crate::{NonEmptyVec, NotEnoughElementsError},
+    std::{
+        alloc::Allocator,
+        convert::TryFrom,
+        ops::{Deref, Index, IndexMut},
+        slice,
+    },
+};
+
+impl<T, A: Allocator> NonEmptyVec<T, A> {
+    /// Create a vec with one element, allocated with `alloc`
+    #[inline]
+    pub fn new_in(first: T, alloc: A) -> Self {
+        Self::with_capacity_in(first, 1, alloc)
+    }
+
+    /// Create a vec with one element and room for at least `capacity`
+    /// elements, allocated with `alloc`
+    #[inline]
+    pub fn with_capacity_in(first: T, capacity: usize, alloc: A) -> Self {
+        let mut vec = Vec::with_capacity_in(capacity.max(1), alloc);
+        vec.push(first);
+        Self { vec }
+    }
+
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.vec.allocator()
+    }
+}
+
+impl<T, A: Allocator> TryFrom<Vec<T, A>> for NonEmptyVec<T, A> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(vec: Vec<T, A>) -> Result<Self, Self::Error> {
+        if vec.is_empty() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self { vec })
+        }
+    }
+}
+
+impl<T, A: Allocator> Deref for NonEmptyVec<T, A> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T, A: Allocator, I: slice::SliceIndex<[T]>> Index<I> for NonEmptyVec<T, A> {
+    type Output = I::Output;
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(self.as_slice(), index)
+    }
+}
+
+impl<T, A: Allocator, I: slice::SliceIndex<[T]>> IndexMut<I> for NonEmptyVec<T, A> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(self.as_mut_slice(), index)
+    }
+}
+
+#[cfg(test)]
+mod allocator_api_tests {
+
+    use {
+        super::*,
+        std::{
+            alloc::{AllocError, Layout, System},
+            cell::Cell,
+            ptr::NonNull,
+        },
+    };
+
+    /// An allocator counting the allocations it does
+    #[derive(Default)]
+    struct Counting {
+        count: Cell<usize>,
+    }
+
+    unsafe impl Allocator for &Counting {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.count.set(self.count.get() + 1);
+            System.allocate(layout)
+        }
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            unsafe { System.deallocate(ptr, layout) }
+        }
+    }
+
+    #[test]
+    fn test_custom_allocator() {
+        let counting = Counting::default();
+        let mut vec = NonEmptyVec::with_capacity_in(1u32, 4, &counting);
+        assert_eq!(counting.count.get(), 1);
+        vec.push(2);
+        vec.push(3);
+        assert_eq!(counting.count.get(), 1);
+        assert_eq!(vec.len().get(), 3);
+        assert_eq!(*vec.last(), 3);
+        assert_eq!(vec.pop(), Some(3));
+        vec[0] = 5;
+        assert_eq!(&vec[..], &[5, 2]);
+        let one = NonEmptyVec::new_in("a", &counting);
+        assert_eq!(counting.count.get(), 2);
+        assert!(one.has_len(1));
+        assert!(std::ptr::eq(*one.allocator(), &counting));
+        let empty: Vec<u8, &Counting> = Vec::new_in(&counting);
+        assert!(NonEmptyVec::<u8, _>::try_from(empty).is_err());
+        let mut full = Vec::new_in(&counting);
+        full.push('x');
+        assert_eq!(
+            *NonEmptyVec::<char, _>::try_from(full).unwrap().first(),
+            'x'
+        );
+    }
+}
@@ -0,0 +1,241 @@
+//! Implementations of [`arbitrary::Arbitrary`], for fuzzing.
+//!
+//! Whatever the input bytes (even none), the generated values respect
+//! the invariants of their types.
+
+use {
+    crate::{
+        AtMostVec, BoundedInt, FiniteF32, FiniteF64, NonEmptyString, NonEmptyVec, OneOrTwo,
+        OneToThree, UnitInterval,
+    },
+    arbitrary::{size_hint, Arbitrary, MaxRecursionReached, Result, Unstructured},
+};
+
+type SizeHint = (usize, Option<usize>);
+
+/// Pick a variant among `count`, the way `#[derive(Arbitrary)]` does
+fn variant_index(u: &mut Unstructured<'_>, count: u64) -> Result<u64> {
+    Ok((u64::from(u32::arbitrary(u)?) * count) >> 32)
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for NonEmptyVec<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut vec = vec![T::arbitrary(u)?];
+        for value in u.arbitrary_iter()? {
+            vec.push(value?);
+        }
+        Ok(Self { vec })
+    }
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        let mut vec = vec![T::arbitrary(&mut u)?];
+        for value in u.arbitrary_take_rest_iter()? {
+            vec.push(value?);
+        }
+        Ok(Self { vec })
+    }
+    fn size_hint(depth: usize) -> SizeHint {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+    fn try_size_hint(depth: usize) -> std::result::Result<SizeHint, MaxRecursionReached> {
+        size_hint::try_recursion_guard(depth, |depth| {
+            Ok(size_hint::and(T::try_size_hint(depth)?, (0, None)))
+        })
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for OneToThree<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match variant_index(u, 3)? {
+            0 => Self::One(T::arbitrary(u)?),
+            1 => Self::Two(T::arbitrary(u)?, T::arbitrary(u)?),
+            _ => Self::Three(T::arbitrary(u)?, T::arbitrary(u)?, T::arbitrary(u)?),
+        })
+    }
+    fn size_hint(depth: usize) -> SizeHint {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+    fn try_size_hint(depth: usize) -> std::result::Result<SizeHint, MaxRecursionReached> {
+        size_hint::try_recursion_guard(depth, |depth| {
+            let t = T::try_size_hint(depth)?;
+            Ok(size_hint::and(
+                u32::size_hint(depth),
+                size_hint::or_all(&[t, size_hint::and(t, t), size_hint::and_all(&[t, t, t])]),
+            ))
+        })
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for OneOrTwo<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match variant_index(u, 2)? {
+            0 => Self::One(T::arbitrary(u)?),
+            _ => Self::Two(T::arbitrary(u)?, T::arbitrary(u)?),
+        })
+    }
+    fn size_hint(depth: usize) -> SizeHint {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+    fn try_size_hint(depth: usize) -> std::result::Result<SizeHint, MaxRecursionReached> {
+        size_hint::try_recursion_guard(depth, |depth| {
+            let t = T::try_size_hint(depth)?;
+            Ok(size_hint::and(
+                u32::size_hint(depth),
+                size_hint::or(t, size_hint::and(t, t)),
+            ))
+        })
+    }
+}
+
+impl<'a, T: Arbitrary<'a>, const MAX: usize> Arbitrary<'a> for AtMostVec<T, MAX> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.arbitrary_len::<T>()?.min(MAX);
+        let mut vec = AtMostVec::new();
+        for _ in 0..len {
+            let _ = vec.try_push(T::arbitrary(u)?);
+        }
+        Ok(vec)
+    }
+    fn size_hint(depth: usize) -> SizeHint {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+    fn try_size_hint(depth: usize) -> std::result::Result<SizeHint, MaxRecursionReached> {
+        size_hint::try_recursion_guard(depth, |depth| {
+            let t = T::try_size_hint(depth)?;
+            Ok((0, t.1.and_then(|t| t.checked_mul(MAX)?.checked_add(8))))
+        })
+    }
+}
+
+impl<'a, const MIN: i64, const MAX: i64> Arbitrary<'a> for BoundedInt<MIN, MAX> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let value = u.int_in_range(MIN..=MAX)?;
+        match Self::try_new(value) {
+            Ok(v) => Ok(v),
+            Err(_) => unreachable!(),
+        }
+    }
+    fn size_hint(_depth: usize) -> SizeHint {
+        (0, Some(8))
+    }
+}
+
+macro_rules! impl_finite_float {
+    ($name:ident, $float:ty) => {
+        /// Non finite floats are replaced with `ZERO`
+        impl<'a> Arbitrary<'a> for $name {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                Ok(Self::try_new(<$float>::arbitrary(u)?).unwrap_or(Self::ZERO))
+            }
+            fn size_hint(depth: usize) -> SizeHint {
+                <$float>::size_hint(depth)
+            }
+        }
+    };
+}
+
+impl_finite_float!(FiniteF32, f32);
+impl_finite_float!(FiniteF64, f64);
+
+impl<'a> Arbitrary<'a> for UnitInterval {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let value = u64::arbitrary(u)? as f64 / u64::MAX as f64;
+        Ok(Self::new_clamped(value))
+    }
+    fn size_hint(depth: usize) -> SizeHint {
+        u64::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for NonEmptyString {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut string = char::arbitrary(u)?.to_string();
+        string.push_str(<&str>::arbitrary(u)?);
+        Ok(Self { string })
+    }
+    fn size_hint(depth: usize) -> SizeHint {
+        size_hint::and(char::size_hint(depth), (0, None))
+    }
+}
+
+#[cfg(test)]
+mod arbitrary_tests {
+
+    use super::*;
+
+    /// a small xorshift generator, good enough to produce varied inputs
+    struct Rng(u64);
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+        fn bytes(&mut self) -> Vec<u8> {
+            let len = (self.next() % 64) as usize;
+            (0..len).map(|_| self.next() as u8).collect()
+        }
+    }
+
+    fn check_all(data: &[u8]) {
+        let mut u = Unstructured::new(data);
+        if let Ok(v) = NonEmptyVec::<u16>::arbitrary(&mut u) {
+            assert!(!v.as_slice().is_empty());
+        }
+        if let Ok(v) = NonEmptyVec::<u8>::arbitrary_take_rest(Unstructured::new(data)) {
+            assert!(!v.as_slice().is_empty());
+        }
+        let mut u = Unstructured::new(data);
+        if let Ok(v) = OneToThree::<i8>::arbitrary(&mut u) {
+            assert!((1..=3).contains(&v.len()));
+        }
+        let mut u = Unstructured::new(data);
+        if let Ok(v) = AtMostVec::<u8, 3>::arbitrary(&mut u) {
+            assert!(v.len() <= 3);
+        }
+        let mut u = Unstructured::new(data);
+        if let Ok(v) = BoundedInt::<-5, 12>::arbitrary(&mut u) {
+            assert!((-5..=12).contains(&v.get()));
+        }
+        let mut u = Unstructured::new(data);
+        if let Ok(v) = FiniteF64::arbitrary(&mut u) {
+            assert!(v.get().is_finite());
+        }
+        let mut u = Unstructured::new(data);
+        if let Ok(v) = UnitInterval::arbitrary(&mut u) {
+            assert!((0.0..=1.0).contains(&v.get()));
+        }
+        let mut u = Unstructured::new(data);
+        if let Ok(v) = NonEmptyString::arbitrary(&mut u) {
+            assert!(!v.as_str().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let mut u = Unstructured::new(&[]);
+        assert!(NonEmptyVec::<u8>::arbitrary(&mut u).unwrap().has_len(1));
+        assert!(OneToThree::<u8>::arbitrary(&mut u).is_ok());
+        assert!(NonEmptyString::arbitrary(&mut u).is_ok());
+        check_all(&[]);
+        check_all(&[0xff]);
+    }
+
+    #[test]
+    fn test_random_inputs() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..5000 {
+            check_all(&rng.bytes());
+        }
+        let variants = (0..300)
+            .filter_map(|_| {
+                let data = rng.bytes();
+                OneToThree::<u8>::arbitrary(&mut Unstructured::new(&data)).ok()
+            })
+            .map(|v| v.len())
+            .collect::<Vec<_>>();
+        for len in 1..=3 {
+            assert!(variants.contains(&len));
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Conversions with [arrayvec](https://docs.rs/arrayvec), so that small
+//! groups built without allocation can be handed out as [`OneToThree`].
+
+use {
+    crate::{NonEmptyVec, NotEnoughElementsError, OneToThree, OneToThreeError},
+    arrayvec::ArrayVec,
+    std::convert::TryFrom,
+};
+
+impl<T, const N: usize> TryFrom<ArrayVec<T, N>> for NonEmptyVec<T> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(vec: ArrayVec<T, N>) -> Result<Self, Self::Error> {
+        Self::try_from(vec.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T, const N: usize> TryFrom<ArrayVec<T, N>> for OneToThree<T> {
+    type Error = OneToThreeError;
+    fn try_from(vec: ArrayVec<T, N>) -> Result<Self, Self::Error> {
+        if vec.len() > 3 {
+            return Err(OneToThreeError::TooMany(vec.len()));
+        }
+        let mut iter = vec.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(a), None, _) => Ok(Self::One(a)),
+            (Some(a), Some(b), None) => Ok(Self::Two(a, b)),
+            (Some(a), Some(b), Some(c)) => Ok(Self::Three(a, b, c)),
+            _ => Err(OneToThreeError::Empty),
+        }
+    }
+}
+
+impl<T> From<OneToThree<T>> for ArrayVec<T, 3> {
+    fn from(few: OneToThree<T>) -> Self {
+        let mut vec = ArrayVec::new();
+        match few {
+            OneToThree::One(a) => vec.push(a),
+            OneToThree::Two(a, b) => {
+                vec.push(a);
+                vec.push(b);
+            }
+            OneToThree::Three(a, b, c) => {
+                vec.push(a);
+                vec.push(b);
+                vec.push(c);
+            }
+        }
+        vec
+    }
+}
+
+#[cfg(test)]
+mod arrayvec_tests {
+
+    use super::*;
+
+    #[test]
+    fn test_one_to_three() {
+        let mut group: ArrayVec<&str, 3> = ArrayVec::new();
+        assert_eq!(
+            OneToThree::<&str>::try_from(group.clone()),
+            Err(OneToThreeError::Empty)
+        );
+        group.push("a");
+        group.push("b");
+        let few = OneToThree::<&str>::try_from(group).unwrap();
+        assert_eq!(few, OneToThree::two("a", "b"));
+        let back: ArrayVec<&str, 3> = few.into();
+        assert_eq!(back.as_slice(), &["a", "b"]);
+        let big: ArrayVec<u8, 5> = [1, 2, 3, 4].into_iter().collect();
+        assert_eq!(
+            OneToThree::<u8>::try_from(big),
+            Err(OneToThreeError::TooMany(4))
+        );
+    }
+
+    #[test]
+    fn test_non_empty_vec() {
+        let vec: ArrayVec<u8, 5> = [1, 2, 3, 4].into_iter().collect();
+        let vec = NonEmptyVec::<u8>::try_from(vec).unwrap();
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+        assert!(NonEmptyVec::<u8>::try_from(ArrayVec::<u8, 2>::new()).is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use {
+    crate::{NotEnoughElementsError, OneToThree},
+    std::{convert::TryFrom, fmt, ops::Deref, slice, vec},
+};
+
+/// Error returned when there are more elements than allowed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyElementsError;
+
+impl fmt::Display for TooManyElementsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "too many elements")
+    }
+}
+
+impl std::error::Error for TooManyElementsError {}
+
+/// A vec with at most `MAX` elements, possibly empty.
+///
+/// Building it from an array which doesn't fit is a compilation error:
+///
+/// ```compile_fail
+/// let _ = strict::AtMostVec::<u8, 2>::from([1, 2, 3]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AtMostVec<T, const MAX: usize> {
+    pub(crate) vec: Vec<T>,
+}
+
+pub(crate) struct AssertFits<const N: usize, const MAX: usize>;
+impl<const N: usize, const MAX: usize> AssertFits<N, MAX> {
+    pub(crate) const OK: () = assert!(N <= MAX, "too many elements");
+}
+
+impl<T, const MAX: usize> AtMostVec<T, MAX> {
+    #[inline]
+    pub fn new() -> Self {
+        Self { vec: Vec::new() }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.vec.len() >= MAX
+    }
+
+    /// Return the number of elements which can still be pushed
+    #[inline]
+    pub fn remaining_capacity(&self) -> usize {
+        MAX - self.vec.len()
+    }
+
+    /// Add the value at the end, or give it back if the vec is full
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            Err(value)
+        } else {
+            self.vec.push(value);
+            Ok(())
+        }
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.vec.pop()
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.vec
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.vec.iter()
+    }
+
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.vec
+    }
+}
+
+impl<T, const MAX: usize> Default for AtMostVec<T, MAX> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const MAX: usize> TryFrom<Vec<T>> for AtMostVec<T, MAX> {
+    type Error = TooManyElementsError;
+    #[inline]
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        if vec.len() > MAX {
+            Err(TooManyElementsError)
+        } else {
+            Ok(Self { vec })
+        }
+    }
+}
+
+/// Only compiles when `N <= MAX`
+impl<T, const N: usize, const MAX: usize> From<[T; N]> for AtMostVec<T, MAX> {
+    #[inline]
+    #[allow(clippy::let_unit_value)]
+    fn from(arr: [T; N]) -> Self {
+        let _ = AssertFits::<N, MAX>::OK;
+        Self {
+            vec: Vec::from(arr),
+        }
+    }
+}
+
+impl<T> From<OneToThree<T>> for AtMostVec<T, 3> {
+    #[inline]
+    fn from(few: OneToThree<T>) -> Self {
+        Self { vec: few.to_vec() }
+    }
+}
+
+/// Fails only when the vec is empty
+impl<T> TryFrom<AtMostVec<T, 3>> for OneToThree<T> {
+    type Error = NotEnoughElementsError;
+    fn try_from(vec: AtMostVec<T, 3>) -> Result<Self, Self::Error> {
+        let mut iter = vec.vec.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(a), None, _) => Ok(Self::One(a)),
+            (Some(a), Some(b), None) => Ok(Self::Two(a, b)),
+            (Some(a), Some(b), Some(c)) => Ok(Self::Three(a, b, c)),
+            _ => Err(NotEnoughElementsError),
+        }
+    }
+}
+
+impl<T, const MAX: usize> From<AtMostVec<T, MAX>> for Vec<T> {
+    #[inline]
+    fn from(vec: AtMostVec<T, MAX>) -> Self {
+        vec.vec
+    }
+}
+
+impl<T, const MAX: usize> Deref for AtMostVec<T, MAX> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T, const MAX: usize> IntoIterator for AtMostVec<T, MAX> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> vec::IntoIter<T> {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, T, const MAX: usize> IntoIterator for &'a AtMostVec<T, MAX> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.vec.iter()
+    }
+}
+
+impl<'a, T, const MAX: usize> IntoIterator for &'a mut AtMostVec<T, MAX> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+    #[inline]
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.vec.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod at_most_vec_tests {
+
+    use super::*;
+
+    #[test]
+    fn test_push() {
+        let mut names = AtMostVec::<&str, 2>::new();
+        assert!(names.is_empty());
+        assert_eq!(names.remaining_capacity(), 2);
+        assert_eq!(names.try_push("Jo"), Ok(()));
+        assert_eq!(names.try_push("Joe"), Ok(()));
+        assert!(names.is_full());
+        assert_eq!(names.remaining_capacity(), 0);
+        assert_eq!(names.try_push("Joseph"), Err("Joseph"));
+        assert_eq!(names.pop(), Some("Joe"));
+        assert_eq!(names.as_slice(), &["Jo"]);
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert!(AtMostVec::<u8, 2>::try_from(vec![1, 2, 3]).is_err());
+        let vec = AtMostVec::<u8, 4>::from([1, 2, 3]);
+        assert_eq!(vec.remaining_capacity(), 1);
+        let empty = AtMostVec::<u8, 0>::from([]);
+        assert!(empty.is_full());
+        let vec: AtMostVec<u8, 3> = AtMostVec::from([5, 6]);
+        assert_eq!(OneToThree::try_from(vec).ok(), Some(OneToThree::Two(5, 6)));
+        assert!(OneToThree::<u8>::try_from(AtMostVec::new()).is_err());
+        let vec = AtMostVec::from(OneToThree::three(1, 2, 3));
+        assert!(vec.is_full());
+    }
+}
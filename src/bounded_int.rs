@@ -0,0 +1,255 @@
+use std::{convert::TryFrom, fmt, num::ParseIntError, str::FromStr};
+
+/// Error returned when a value isn't in the allowed range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRangeError;
+
+impl fmt::Display for OutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "value out of range")
+    }
+}
+
+impl std::error::Error for OutOfRangeError {}
+
+/// Error returned when parsing a [`BoundedInt`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBoundedIntError {
+    Invalid(ParseIntError),
+    OutOfRange(OutOfRangeError),
+}
+
+impl fmt::Display for ParseBoundedIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Invalid(e) => e.fmt(f),
+            Self::OutOfRange(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseBoundedIntError {}
+
+/// An integer in `MIN..=MAX`.
+///
+/// ```
+/// use strict::BoundedInt;
+///
+/// type Month = BoundedInt<1, 12>;
+/// const MARCH: Month = Month::new::<3>();
+/// let month = Month::try_from(3).unwrap();
+/// match month {
+///     MARCH => println!("spring is coming"),
+///     _ => {}
+/// }
+/// assert!(Month::try_from(13).is_err());
+/// ```
+///
+/// Having `MIN > MAX`, or calling `new` with a value out of bounds,
+/// is a compilation error:
+///
+/// ```compile_fail
+/// let _ = strict::BoundedInt::<1, 12>::new::<13>();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedInt<const MIN: i64, const MAX: i64> {
+    value: i64,
+}
+
+struct AssertInRange<const V: i64, const MIN: i64, const MAX: i64>;
+impl<const V: i64, const MIN: i64, const MAX: i64> AssertInRange<V, MIN, MAX> {
+    const OK: () = assert!(MIN <= V && V <= MAX, "value out of bounds");
+}
+
+impl<const MIN: i64, const MAX: i64> BoundedInt<MIN, MAX> {
+    const BOUNDS_OK: () = assert!(MIN <= MAX, "MIN must not be greater than MAX");
+
+    pub const MIN: Self = Self::new::<MIN>();
+    pub const MAX: Self = Self::new::<MAX>();
+
+    /// Build a value checked at compile time
+    #[allow(clippy::let_unit_value)]
+    pub const fn new<const V: i64>() -> Self {
+        let _ = Self::BOUNDS_OK;
+        let _ = AssertInRange::<V, MIN, MAX>::OK;
+        Self { value: V }
+    }
+
+    #[allow(clippy::let_unit_value)]
+    pub const fn try_new(value: i64) -> Result<Self, OutOfRangeError> {
+        let _ = Self::BOUNDS_OK;
+        if MIN <= value && value <= MAX {
+            Ok(Self { value })
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    /// Build a value, clamping it in `MIN..=MAX`
+    #[allow(clippy::let_unit_value)]
+    pub const fn new_saturating(value: i64) -> Self {
+        let _ = Self::BOUNDS_OK;
+        if value < MIN {
+            Self { value: MIN }
+        } else if value > MAX {
+            Self { value: MAX }
+        } else {
+            Self { value }
+        }
+    }
+
+    #[inline]
+    pub const fn get(self) -> i64 {
+        self.value
+    }
+
+    #[inline]
+    pub fn checked_add(self, rhs: i64) -> Option<Self> {
+        self.value
+            .checked_add(rhs)
+            .and_then(|v| Self::try_new(v).ok())
+    }
+
+    #[inline]
+    pub fn checked_sub(self, rhs: i64) -> Option<Self> {
+        self.value
+            .checked_sub(rhs)
+            .and_then(|v| Self::try_new(v).ok())
+    }
+
+    #[inline]
+    pub fn checked_mul(self, rhs: i64) -> Option<Self> {
+        self.value
+            .checked_mul(rhs)
+            .and_then(|v| Self::try_new(v).ok())
+    }
+
+    #[inline]
+    pub fn saturating_add(self, rhs: i64) -> Self {
+        Self::new_saturating(self.value.saturating_add(rhs))
+    }
+
+    #[inline]
+    pub fn saturating_sub(self, rhs: i64) -> Self {
+        Self::new_saturating(self.value.saturating_sub(rhs))
+    }
+
+    #[inline]
+    pub fn saturating_mul(self, rhs: i64) -> Self {
+        Self::new_saturating(self.value.saturating_mul(rhs))
+    }
+
+    /// Add, wrapping around the bounds (`MAX + 1` gives `MIN`)
+    #[inline]
+    pub fn wrapping_add(self, rhs: i64) -> Self {
+        let span = (MAX as i128) - (MIN as i128) + 1;
+        let offset = (self.value as i128) - (MIN as i128) + (rhs as i128);
+        Self {
+            value: (MIN as i128 + offset.rem_euclid(span)) as i64,
+        }
+    }
+
+    /// Subtract, wrapping around the bounds (`MIN - 1` gives `MAX`)
+    #[inline]
+    pub fn wrapping_sub(self, rhs: i64) -> Self {
+        let span = (MAX as i128) - (MIN as i128) + 1;
+        let offset = (self.value as i128) - (MIN as i128) - (rhs as i128);
+        Self {
+            value: (MIN as i128 + offset.rem_euclid(span)) as i64,
+        }
+    }
+}
+
+macro_rules! impl_try_from_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl<const MIN: i64, const MAX: i64> TryFrom<$t> for BoundedInt<MIN, MAX> {
+                type Error = OutOfRangeError;
+                #[inline]
+                fn try_from(value: $t) -> Result<Self, Self::Error> {
+                    i64::try_from(value)
+                        .map_err(|_| OutOfRangeError)
+                        .and_then(Self::try_new)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_primitive!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl<const MIN: i64, const MAX: i64> From<BoundedInt<MIN, MAX>> for i64 {
+    #[inline]
+    fn from(b: BoundedInt<MIN, MAX>) -> Self {
+        b.value
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> fmt::Display for BoundedInt<MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> FromStr for BoundedInt<MIN, MAX> {
+    type Err = ParseBoundedIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: i64 = s.parse().map_err(ParseBoundedIntError::Invalid)?;
+        Self::try_new(value).map_err(ParseBoundedIntError::OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod bounded_int_tests {
+
+    use super::*;
+
+    type Percent = BoundedInt<0, 100>;
+    type Month = BoundedInt<1, 12>;
+
+    static DEFAULT_MONTH: Month = Month::new::<1>();
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Percent::try_from(100u8).unwrap().get(), 100);
+        assert!(Percent::try_from(101i32).is_err());
+        assert!(Percent::try_from(-1i64).is_err());
+        assert!(Percent::try_from(u64::MAX).is_err());
+        assert_eq!(Month::MIN, DEFAULT_MONTH);
+        assert_eq!(Month::MAX.get(), 12);
+        assert_eq!(i64::from(Month::new::<7>()), 7);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let m = Month::new::<11>();
+        assert_eq!(m.checked_add(1), Some(Month::MAX));
+        assert_eq!(m.checked_add(2), None);
+        assert_eq!(m.checked_sub(11), None);
+        assert_eq!(m.checked_mul(2), None);
+        assert_eq!(m.saturating_add(5), Month::MAX);
+        assert_eq!(m.saturating_sub(50), Month::MIN);
+        assert_eq!(m.saturating_mul(-1), Month::MIN);
+        assert_eq!(m.wrapping_add(2), Month::new::<1>());
+        assert_eq!(m.wrapping_add(25), Month::new::<12>());
+        assert_eq!(Month::MIN.wrapping_sub(1), Month::MAX);
+        assert_eq!(Month::MIN.wrapping_sub(i64::MAX).get(), 6);
+        type Full = BoundedInt<{ i64::MIN }, { i64::MAX }>;
+        assert_eq!(Full::MAX.wrapping_add(1), Full::MIN);
+    }
+
+    #[test]
+    fn test_parse_display() {
+        let p: Percent = "42".parse().unwrap();
+        assert_eq!(p.to_string(), "42");
+        assert!(matches!(
+            "142".parse::<Percent>(),
+            Err(ParseBoundedIntError::OutOfRange(_)),
+        ));
+        assert!(matches!(
+            "x".parse::<Percent>(),
+            Err(ParseBoundedIntError::Invalid(_)),
+        ));
+        assert!(Percent::new::<3>() < Percent::new::<30>());
+    }
+}
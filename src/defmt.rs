@@ -0,0 +1,53 @@
+//! Implementations of [`defmt::Format`], for logging on embedded targets.
+//!
+//! The collections are formatted like the corresponding slices.
+
+use {
+    crate::{NonEmptySlice, NonEmptyVec, NotEnoughElementsError, OneToThree},
+    defmt::{Format, Formatter},
+};
+
+impl<T: Format> Format for NonEmptyVec<T> {
+    fn format(&self, f: Formatter<'_>) {
+        self.as_slice().format(f)
+    }
+}
+
+impl<T: Format> Format for NonEmptySlice<'_, T> {
+    fn format(&self, f: Formatter<'_>) {
+        self.as_slice().format(f)
+    }
+}
+
+impl<T: Format> Format for OneToThree<T> {
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            Self::One(a) => [a].format(f),
+            Self::Two(a, b) => [a, b].format(f),
+            Self::Three(a, b, c) => [a, b, c].format(f),
+        }
+    }
+}
+
+impl Format for NotEnoughElementsError {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "not enough elements")
+    }
+}
+
+#[cfg(test)]
+mod defmt_tests {
+
+    use super::*;
+
+    /// defmt needs a target runtime, so we only check the impls exist
+    fn assert_format<T: Format + ?Sized>() {}
+
+    #[test]
+    fn test_impls() {
+        assert_format::<NonEmptyVec<u8>>();
+        assert_format::<NonEmptySlice<'_, i32>>();
+        assert_format::<OneToThree<NonEmptyVec<u16>>>();
+        assert_format::<NotEnoughElementsError>();
+    }
+}
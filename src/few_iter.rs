@@ -0,0 +1,25 @@
+/// A small ordered collection whose items are accessed by index
+pub trait Few {
+    type Item;
+    fn get(&self, i: usize) -> Option<&Self::Item>;
+}
+
+/// An iterator over the items of a [`crate::OneToThree`] or of a
+/// [`crate::OneOrTwo`]
+pub struct FewIter<'i, F> {
+    idx: usize,
+    few: &'i F,
+}
+impl<'i, F> FewIter<'i, F> {
+    pub fn new(few: &'i F) -> Self {
+        Self { idx: 0, few }
+    }
+}
+impl<'i, F: Few> Iterator for FewIter<'i, F> {
+    type Item = &'i F::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.idx;
+        self.idx += 1;
+        self.few.get(i)
+    }
+}
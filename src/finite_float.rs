@@ -0,0 +1,236 @@
+use std::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt, hash,
+    num::ParseFloatError,
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+/// Error returned when a float is NaN or infinite
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotFiniteError;
+
+impl fmt::Display for NotFiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "value isn't finite")
+    }
+}
+
+impl std::error::Error for NotFiniteError {}
+
+/// Error returned when parsing a finite float
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseFiniteFloatError {
+    Invalid(ParseFloatError),
+    NotFinite(NotFiniteError),
+}
+
+impl fmt::Display for ParseFiniteFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Invalid(e) => e.fmt(f),
+            Self::NotFinite(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseFiniteFloatError {}
+
+macro_rules! finite_float {
+    ($name:ident, $f:ty) => {
+        /// A float which is neither NaN nor infinite, and thus can be
+        /// totally ordered and hashed.
+        ///
+        /// `-0.0` and `0.0` are equal, and hash the same.
+        ///
+        /// Arithmetic operations return a `Result` as they may overflow
+        /// to infinity or produce NaN (e.g. `0.0 / 0.0`).
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $name {
+            value: $f,
+        }
+
+        impl $name {
+            pub const ZERO: Self = Self { value: 0.0 };
+            pub const ONE: Self = Self { value: 1.0 };
+            pub const MIN: Self = Self { value: <$f>::MIN };
+            pub const MAX: Self = Self { value: <$f>::MAX };
+
+            #[inline]
+            pub fn try_new(value: $f) -> Result<Self, NotFiniteError> {
+                if value.is_finite() {
+                    Ok(Self { value })
+                } else {
+                    Err(NotFiniteError)
+                }
+            }
+
+            #[inline]
+            pub const fn get(self) -> $f {
+                self.value
+            }
+
+            #[inline]
+            pub fn abs(self) -> Self {
+                Self {
+                    value: self.value.abs(),
+                }
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                match self.value.partial_cmp(&other.value) {
+                    Some(ord) => ord,
+                    None => unreachable!(),
+                }
+            }
+        }
+
+        impl hash::Hash for $name {
+            #[inline]
+            fn hash<H: hash::Hasher>(&self, state: &mut H) {
+                // adding 0.0 turns -0.0 into 0.0
+                (self.value + 0.0).to_bits().hash(state);
+            }
+        }
+
+        impl TryFrom<$f> for $name {
+            type Error = NotFiniteError;
+            #[inline]
+            fn try_from(value: $f) -> Result<Self, Self::Error> {
+                Self::try_new(value)
+            }
+        }
+
+        impl From<$name> for $f {
+            #[inline]
+            fn from(f: $name) -> Self {
+                f.value
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self { value: -self.value }
+            }
+        }
+
+        finite_float!(@op $name, Add, add, +);
+        finite_float!(@op $name, Sub, sub, -);
+        finite_float!(@op $name, Mul, mul, *);
+        finite_float!(@op $name, Div, div, /);
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.value.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseFiniteFloatError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let value: $f = s.parse().map_err(ParseFiniteFloatError::Invalid)?;
+                Self::try_new(value).map_err(ParseFiniteFloatError::NotFinite)
+            }
+        }
+    };
+    (@op $name:ident, $trait:ident, $method:ident, $op:tt) => {
+        impl $trait for $name {
+            type Output = Result<Self, NotFiniteError>;
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self::try_new(self.value $op rhs.value)
+            }
+        }
+    };
+}
+
+finite_float!(FiniteF32, f32);
+finite_float!(FiniteF64, f64);
+
+impl From<FiniteF32> for FiniteF64 {
+    #[inline]
+    fn from(f: FiniteF32) -> Self {
+        Self {
+            value: f.value as f64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod finite_float_tests {
+
+    use {
+        super::*,
+        crate::{NonEmptyIterator, NonEmptyVec, OneToThree},
+        std::collections::HashSet,
+    };
+
+    fn f(v: f64) -> FiniteF64 {
+        FiniteF64::try_new(v).unwrap()
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert!(FiniteF64::try_from(f64::NAN).is_err());
+        assert!(FiniteF64::try_from(f64::INFINITY).is_err());
+        assert!(FiniteF32::try_from(f32::NEG_INFINITY).is_err());
+        assert_eq!(f64::from(f(2.5)), 2.5);
+        assert_eq!(FiniteF64::from(FiniteF32::try_new(0.5).unwrap()), f(0.5));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(f(1.5) + f(2.0), Ok(f(3.5)));
+        assert_eq!(f(1.5) - f(2.0), Ok(f(-0.5)));
+        assert_eq!(FiniteF64::MAX * f(2.0), Err(NotFiniteError));
+        assert_eq!(f(1.0) / FiniteF64::ZERO, Err(NotFiniteError));
+        assert_eq!(FiniteF64::ZERO / FiniteF64::ZERO, Err(NotFiniteError));
+        assert_eq!(-f(3.0), f(-3.0));
+    }
+
+    #[test]
+    fn test_ord_hash() {
+        assert_eq!(f(-0.0), f(0.0));
+        let set: HashSet<FiniteF64> = [f(-0.0), f(0.0), f(1.0)].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        let vec: NonEmptyVec<FiniteF64> = vec![f(3.0), f(-1.0), f(7.5)].try_into().unwrap();
+        assert_eq!(*vec.iter_ne().max(), f(7.5));
+        assert_eq!(
+            OneToThree::three(f(2.0), f(-4.0), f(1.0)).sorted(),
+            OneToThree::three(f(-4.0), f(1.0), f(2.0)),
+        );
+    }
+
+    #[test]
+    fn test_parse_display() {
+        let v: FiniteF64 = "1.25".parse().unwrap();
+        assert_eq!(v.to_string(), "1.25");
+        assert!(matches!(
+            "inf".parse::<FiniteF64>(),
+            Err(ParseFiniteFloatError::NotFinite(_)),
+        ));
+        assert!(matches!(
+            "NaN".parse::<FiniteF32>(),
+            Err(ParseFiniteFloatError::NotFinite(_)),
+        ));
+        assert!(matches!(
+            "one".parse::<FiniteF32>(),
+            Err(ParseFiniteFloatError::Invalid(_)),
+        ));
+    }
+}
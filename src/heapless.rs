@@ -0,0 +1,124 @@
+//! Conversions with [heapless](https://docs.rs/heapless) vecs, which don't
+//! need an allocator.
+//!
+//! On targets without `Vec`, a `heapless::Vec` can be checked and
+//! borrowed as a [`NonEmptySlice`].
+
+use {
+    crate::{
+        at_most_vec::AssertFits, AtMostVec, NonEmptySlice, NotEnoughElementsError, OneToThree,
+        OneToThreeError,
+    },
+    heapless::Vec as HVec,
+    std::convert::TryFrom,
+};
+
+impl<'a, T, const N: usize> TryFrom<&'a HVec<T, N>> for NonEmptySlice<'a, T> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(vec: &'a HVec<T, N>) -> Result<Self, Self::Error> {
+        Self::try_from(vec.as_slice())
+    }
+}
+
+impl<T, const N: usize> TryFrom<HVec<T, N>> for OneToThree<T> {
+    type Error = OneToThreeError;
+    fn try_from(vec: HVec<T, N>) -> Result<Self, Self::Error> {
+        if vec.len() > 3 {
+            return Err(OneToThreeError::TooMany(vec.len()));
+        }
+        let mut iter = vec.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(a), None, _) => Ok(Self::One(a)),
+            (Some(a), Some(b), None) => Ok(Self::Two(a, b)),
+            (Some(a), Some(b), Some(c)) => Ok(Self::Three(a, b, c)),
+            _ => Err(OneToThreeError::Empty),
+        }
+    }
+}
+
+impl<T> From<OneToThree<T>> for HVec<T, 3> {
+    fn from(few: OneToThree<T>) -> Self {
+        let mut vec = HVec::new();
+        let (a, b, c) = match few {
+            OneToThree::One(a) => (a, None, None),
+            OneToThree::Two(a, b) => (a, Some(b), None),
+            OneToThree::Three(a, b, c) => (a, Some(b), Some(c)),
+        };
+        for value in std::iter::once(a).chain(b).chain(c) {
+            if vec.push(value).is_err() {
+                unreachable!();
+            }
+        }
+        vec
+    }
+}
+
+/// Only compiles when `N <= MAX`
+impl<T, const N: usize, const MAX: usize> From<HVec<T, N>> for AtMostVec<T, MAX> {
+    #[inline]
+    #[allow(clippy::let_unit_value)]
+    fn from(vec: HVec<T, N>) -> Self {
+        let _ = AssertFits::<N, MAX>::OK;
+        Self {
+            vec: vec.into_iter().collect(),
+        }
+    }
+}
+
+impl<T, const MAX: usize> From<AtMostVec<T, MAX>> for HVec<T, MAX> {
+    fn from(vec: AtMostVec<T, MAX>) -> Self {
+        let mut hvec = HVec::new();
+        for value in vec.vec {
+            if hvec.push(value).is_err() {
+                unreachable!();
+            }
+        }
+        hvec
+    }
+}
+
+#[cfg(test)]
+mod heapless_tests {
+
+    use super::*;
+
+    fn hvec<const N: usize>(values: &[u8]) -> HVec<u8, N> {
+        HVec::from_slice(values).unwrap()
+    }
+
+    #[test]
+    fn test_one_to_three() {
+        assert_eq!(
+            OneToThree::<u8>::try_from(hvec::<4>(&[1, 2])),
+            Ok(OneToThree::two(1, 2))
+        );
+        assert_eq!(
+            OneToThree::<u8>::try_from(hvec::<8>(&[1, 2, 3])),
+            Ok(OneToThree::three(1, 2, 3))
+        );
+        assert_eq!(
+            OneToThree::<u8>::try_from(hvec::<8>(&[])),
+            Err(OneToThreeError::Empty)
+        );
+        assert_eq!(
+            OneToThree::<u8>::try_from(hvec::<8>(&[1, 2, 3, 4])),
+            Err(OneToThreeError::TooMany(4))
+        );
+        let back: HVec<u8, 3> = OneToThree::three(7, 8, 9).into();
+        assert_eq!(back.as_slice(), &[7, 8, 9]);
+    }
+
+    #[test]
+    fn test_slice_and_at_most() {
+        let vec = hvec::<4>(&[5, 6]);
+        let slice = NonEmptySlice::<u8>::try_from(&vec).unwrap();
+        assert_eq!(*slice.last(), 6);
+        assert!(NonEmptySlice::<u8>::try_from(&hvec::<4>(&[])).is_err());
+        let at_most: AtMostVec<u8, 6> = vec.into();
+        assert_eq!(at_most.as_slice(), &[5, 6]);
+        let back: HVec<u8, 6> = at_most.into();
+        assert_eq!(back.capacity(), 6);
+        assert_eq!(back.as_slice(), &[5, 6]);
+    }
+}
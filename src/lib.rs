@@ -1,7 +1,73 @@
 //! Provide some types with inherent bounds, useful when you want to avoid unwrap or want const
 //! matching.
 //!
+//! With the `no_unsafe` feature, the crate contains no unsafe block: the
+//! accessors check the bounds, and the only unsafe items, explicitly
+//! allowed, are the `new_unchecked` constructors, kept so that the API
+//! doesn't depend on the features, and the rkyv `Verify` impl.
+#![cfg_attr(feature = "no_unsafe", deny(unsafe_code))]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
+#[cfg(feature = "allocator_api")]
+mod allocator_api;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+mod at_most_vec;
+mod bounded_int;
+#[cfg(feature = "defmt")]
+mod defmt;
+mod few_iter;
+mod finite_float;
+#[cfg(feature = "heapless")]
+mod heapless;
+mod non_blank_string;
+mod non_empty;
+mod non_empty_arc_slice;
+mod non_empty_binary_heap;
+mod non_empty_boxed_slice;
+#[cfg(feature = "bytes")]
+mod non_empty_bytes;
+mod non_empty_cow;
+mod non_empty_iter;
+mod non_empty_path;
+mod non_empty_range;
+mod non_empty_slice;
+mod non_empty_slice_mut;
+#[cfg(feature = "smallvec")]
+mod non_empty_small_vec;
+mod non_empty_string;
 mod non_empty_vec;
+mod one_or_two;
 mod one_to_three;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "rkyv")]
+pub use crate::rkyv::ArchivedNonEmptyVec;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod unchecked;
+mod unique_non_empty_vec;
+mod unit_interval;
 
-pub use {non_empty_vec::*, one_to_three::*};
+pub use few_iter::FewIter;
+#[cfg(feature = "bytes")]
+pub use non_empty_bytes::*;
+#[cfg(feature = "smallvec")]
+pub use non_empty_small_vec::*;
+pub use {
+    at_most_vec::*, bounded_int::*, finite_float::*, non_blank_string::*, non_empty::*,
+    non_empty_arc_slice::*, non_empty_binary_heap::*, non_empty_boxed_slice::*, non_empty_cow::*,
+    non_empty_iter::*, non_empty_path::*, non_empty_range::*, non_empty_slice::*,
+    non_empty_slice_mut::*, non_empty_string::*, non_empty_vec::*, one_or_two::*, one_to_three::*,
+    unique_non_empty_vec::*, unit_interval::*,
+};
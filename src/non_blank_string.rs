@@ -0,0 +1,245 @@
+use {
+    crate::NonEmptyString,
+    std::{convert::TryFrom, fmt, ops::Deref},
+};
+
+/// Error returned when a string is empty or made only of whitespaces
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlankStringError;
+
+impl fmt::Display for BlankStringError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "string is blank")
+    }
+}
+
+impl std::error::Error for BlankStringError {}
+
+/// Return the bounds of the trimmed part, if not empty
+fn trimmed_bounds(s: &str) -> Option<(usize, usize)> {
+    let start = s.len() - s.trim_start().len();
+    let end = s.trim_end().len();
+    if start < end {
+        Some((start, end))
+    } else {
+        None
+    }
+}
+
+/// A string which contains at least one non whitespace character.
+///
+/// The original string is kept (and is what's displayed and compared),
+/// while the [`trimmed`](NonBlankString::trimmed) view is guaranteed
+/// to be non empty.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonBlankString {
+    string: String,
+    start: usize,
+    end: usize,
+}
+
+impl NonBlankString {
+    /// the original string, possibly with leading or trailing whitespaces
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    /// the string without leading and trailing whitespaces, never empty
+    #[inline]
+    pub fn trimmed(&self) -> &str {
+        &self.string[self.start..self.end]
+    }
+
+    #[inline]
+    pub fn as_non_blank_str(&self) -> NonBlankStr<'_> {
+        NonBlankStr {
+            str: &self.string,
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.string
+    }
+}
+
+impl TryFrom<String> for NonBlankString {
+    type Error = BlankStringError;
+    #[inline]
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        match trimmed_bounds(&string) {
+            Some((start, end)) => Ok(Self { string, start, end }),
+            None => Err(BlankStringError),
+        }
+    }
+}
+
+impl TryFrom<&str> for NonBlankString {
+    type Error = BlankStringError;
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        NonBlankStr::try_from(s).map(|s| s.to_non_blank_string())
+    }
+}
+
+impl TryFrom<NonEmptyString> for NonBlankString {
+    type Error = BlankStringError;
+    #[inline]
+    fn try_from(s: NonEmptyString) -> Result<Self, Self::Error> {
+        Self::try_from(s.string)
+    }
+}
+
+impl From<NonBlankString> for NonEmptyString {
+    #[inline]
+    fn from(s: NonBlankString) -> Self {
+        NonEmptyString { string: s.string }
+    }
+}
+
+impl From<NonBlankString> for String {
+    #[inline]
+    fn from(s: NonBlankString) -> Self {
+        s.string
+    }
+}
+
+impl Deref for NonBlankString {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.string
+    }
+}
+
+impl fmt::Display for NonBlankString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.string.fmt(f)
+    }
+}
+
+impl PartialEq<str> for NonBlankString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.string == other
+    }
+}
+
+impl PartialEq<&str> for NonBlankString {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.string == *other
+    }
+}
+
+/// A borrowed string which contains at least one non whitespace character
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonBlankStr<'a> {
+    str: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> NonBlankStr<'a> {
+    /// the original string, possibly with leading or trailing whitespaces
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.str
+    }
+
+    /// the string without leading and trailing whitespaces, never empty
+    #[inline]
+    pub fn trimmed(&self) -> &'a str {
+        &self.str[self.start..self.end]
+    }
+
+    #[inline]
+    pub fn to_non_blank_string(&self) -> NonBlankString {
+        NonBlankString {
+            string: self.str.to_string(),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for NonBlankStr<'a> {
+    type Error = BlankStringError;
+    #[inline]
+    fn try_from(str: &'a str) -> Result<Self, Self::Error> {
+        match trimmed_bounds(str) {
+            Some((start, end)) => Ok(Self { str, start, end }),
+            None => Err(BlankStringError),
+        }
+    }
+}
+
+impl Deref for NonBlankStr<'_> {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &str {
+        self.str
+    }
+}
+
+impl fmt::Display for NonBlankStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.str.fmt(f)
+    }
+}
+
+impl PartialEq<str> for NonBlankStr<'_> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.str == other
+    }
+}
+
+impl PartialEq<&str> for NonBlankStr<'_> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.str == *other
+    }
+}
+
+#[cfg(test)]
+mod non_blank_string_tests {
+
+    use super::*;
+
+    #[test]
+    fn test_blank() {
+        assert!(NonBlankString::try_from("").is_err());
+        assert!(NonBlankString::try_from("  \t\n ").is_err());
+        assert!(NonBlankStr::try_from("\u{2003}").is_err()); // em space
+        let empty_but_not_blank = NonEmptyString::try_from("   ").unwrap();
+        assert!(NonBlankString::try_from(empty_but_not_blank).is_err());
+    }
+
+    #[test]
+    fn test_trimmed() {
+        let s = NonBlankString::try_from("  Jane Doe\n".to_string()).unwrap();
+        assert_eq!(s.trimmed(), "Jane Doe");
+        assert_eq!(s.as_str(), "  Jane Doe\n");
+        assert_eq!(s, "  Jane Doe\n");
+        assert_eq!(s.to_string(), "  Jane Doe\n");
+        let s = NonBlankStr::try_from("é ").unwrap();
+        assert_eq!(s.trimmed(), "é");
+        assert_eq!(s.to_non_blank_string().trimmed(), "é");
+        let s = NonBlankString::try_from("x").unwrap();
+        assert_eq!(s.trimmed(), "x");
+        assert_eq!(s.as_non_blank_str().trimmed(), "x");
+    }
+
+    #[test]
+    fn test_non_empty_string_conversion() {
+        let s = NonBlankString::try_from(" a ").unwrap();
+        let s = NonEmptyString::from(s);
+        assert_eq!(s, " a ");
+        let s = NonBlankString::try_from(s).unwrap();
+        assert_eq!(s.trimmed(), "a");
+    }
+}
@@ -0,0 +1,78 @@
+use std::num::NonZeroUsize;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+pub(crate) use sealed::Sealed;
+
+/// A collection with at least one element.
+///
+/// This lets functions accept any of the non empty collections of this
+/// crate, e.g. `impl NonEmpty<Item = Row>`.
+///
+/// The trait is sealed: it can't be implemented outside of this crate,
+/// as code skipping the checks relies on `iter()` yielding `len()` items,
+/// and on `len()` being at least 1:
+///
+/// ```compile_fail
+/// use std::{iter, num::NonZeroUsize};
+/// struct Liar;
+/// impl strict::NonEmpty for Liar {
+///     type Item = u8;
+///     type Iter<'a> = iter::Empty<&'a u8>;
+///     fn len(&self) -> NonZeroUsize { NonZeroUsize::new(1).unwrap() }
+///     fn first(&self) -> &u8 { &0 }
+///     fn last(&self) -> &u8 { &0 }
+///     fn iter(&self) -> iter::Empty<&u8> { iter::empty() }
+/// }
+/// ```
+#[allow(clippy::len_without_is_empty)]
+pub trait NonEmpty: Sealed {
+    type Item;
+    type Iter<'a>: Iterator<Item = &'a Self::Item>
+    where
+        Self: 'a;
+    fn len(&self) -> NonZeroUsize;
+    fn first(&self) -> &Self::Item;
+    fn last(&self) -> &Self::Item;
+    fn iter(&self) -> Self::Iter<'_>;
+}
+
+#[cfg(test)]
+mod non_empty_tests {
+
+    use {
+        super::*,
+        crate::{NonEmptySlice, NonEmptyVec, OneOrTwo, OneToThree},
+        std::convert::TryFrom,
+    };
+
+    fn describe<C: NonEmpty<Item = u8>>(c: &C) -> String {
+        let sum: u32 = c.iter().map(|&v| u32::from(v)).sum();
+        format!(
+            "{} items from {} to {}, sum {}",
+            c.len(),
+            c.first(),
+            c.last(),
+            sum
+        )
+    }
+
+    #[test]
+    fn test_generic_use() {
+        let vec = NonEmptyVec::<u8>::try_from(vec![1, 2, 3]).unwrap();
+        assert_eq!(describe(&vec), "3 items from 1 to 3, sum 6");
+        let slice = NonEmptySlice::try_from(&vec[1..]).unwrap();
+        assert_eq!(describe(&slice), "2 items from 2 to 3, sum 5");
+        assert_eq!(
+            describe(&OneToThree::three(4, 5, 6)),
+            "3 items from 4 to 6, sum 15"
+        );
+        assert_eq!(describe(&OneToThree::one(7)), "1 items from 7 to 7, sum 7");
+        assert_eq!(
+            describe(&OneOrTwo::two(8, 9)),
+            "2 items from 8 to 9, sum 17"
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use {
+    crate::{
+        non_empty_vec::range_to_bounds, unchecked, NonEmpty, NonEmptySlice, NonEmptyVec,
+        NotEnoughElementsError,
+    },
+    std::{
+        convert::TryFrom,
+        fmt, hash,
+        num::NonZeroUsize,
+        ops::{Deref, RangeBounds},
+        slice,
+        sync::Arc,
+    },
+};
+
+/// A shared, immutable and cheaply cloneable non empty slice.
+///
+/// Sub-slicing with [`NonEmptyArcSlice::slice`] doesn't copy: the new
+/// value shares the same allocation, only its window differs.
+pub struct NonEmptyArcSlice<T> {
+    arc: Arc<[T]>,
+    start: usize,
+    len: NonZeroUsize,
+}
+
+impl<T> NonEmptyArcSlice<T> {
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        self.len
+    }
+
+    #[inline]
+    pub fn has_len(&self, len: usize) -> bool {
+        self.len.get() == len
+    }
+
+    #[inline]
+    pub fn first(&self) -> &T {
+        unchecked::get(&self.arc, self.start)
+    }
+
+    #[inline]
+    pub fn last(&self) -> &T {
+        unchecked::get(&self.arc, self.start + self.len.get() - 1)
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unchecked::get_range(&self.arc, self.start..self.start + self.len.get())
+    }
+
+    #[inline]
+    pub fn as_non_empty_slice(&self) -> NonEmptySlice<'_, T> {
+        NonEmptySlice {
+            slice: self.as_slice(),
+        }
+    }
+
+    /// Return a new arc slice sharing the same allocation, restricted
+    /// to the given range (relative to this slice).
+    ///
+    /// Fails if the range is empty, panics if it's out of bounds.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Result<Self, NotEnoughElementsError> {
+        let (start, end) = range_to_bounds(range, self.len.get());
+        match NonZeroUsize::new(end - start) {
+            Some(len) => Ok(Self {
+                arc: Arc::clone(&self.arc),
+                start: self.start + start,
+                len,
+            }),
+            None => Err(NotEnoughElementsError),
+        }
+    }
+
+    /// Tell whether both arc slices share the same allocation (their
+    /// windows may differ)
+    #[inline]
+    pub fn shares_allocation(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.arc, &other.arc)
+    }
+}
+
+impl<T> Clone for NonEmptyArcSlice<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            arc: Arc::clone(&self.arc),
+            start: self.start,
+            len: self.len,
+        }
+    }
+}
+
+impl<T> TryFrom<Arc<[T]>> for NonEmptyArcSlice<T> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(arc: Arc<[T]>) -> Result<Self, Self::Error> {
+        match NonZeroUsize::new(arc.len()) {
+            Some(len) => Ok(Self { arc, start: 0, len }),
+            None => Err(NotEnoughElementsError),
+        }
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for NonEmptyArcSlice<T> {
+    #[inline]
+    fn from(vec: NonEmptyVec<T>) -> Self {
+        let len = vec.len();
+        Self {
+            arc: Arc::from(vec.vec),
+            start: 0,
+            len,
+        }
+    }
+}
+
+impl<T> Deref for NonEmptyArcSlice<T> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for NonEmptyArcSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for NonEmptyArcSlice<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for NonEmptyArcSlice<T> {}
+
+impl<T: hash::Hash> hash::Hash for NonEmptyArcSlice<T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T> crate::non_empty::Sealed for NonEmptyArcSlice<T> {}
+
+impl<T> NonEmpty for NonEmptyArcSlice<T> {
+    type Item = T;
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        T: 'a;
+    #[inline]
+    fn len(&self) -> NonZeroUsize {
+        self.len()
+    }
+    #[inline]
+    fn first(&self) -> &T {
+        self.first()
+    }
+    #[inline]
+    fn last(&self) -> &T {
+        self.last()
+    }
+    #[inline]
+    fn iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+}
+
+#[cfg(test)]
+mod non_empty_arc_slice_tests {
+
+    use {super::*, std::thread};
+
+    #[test]
+    fn test_slice() {
+        let arc: Arc<[u32]> = Arc::from(vec![0, 1, 2, 3, 4, 5]);
+        let all = NonEmptyArcSlice::try_from(arc).unwrap();
+        let mid = all.slice(1..5).unwrap();
+        assert_eq!(mid.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(*mid.first(), 1);
+        assert_eq!(*mid.last(), 4);
+        let sub = mid.slice(2..).unwrap();
+        assert_eq!(sub.as_slice(), &[3, 4]);
+        let one = sub.slice(..=0).unwrap();
+        assert!(one.has_len(1));
+        assert_eq!(one.first(), one.last());
+        assert!(one.shares_allocation(&all));
+        assert!(mid.slice(2..2).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_out_of_bounds() {
+        let all = NonEmptyArcSlice::from(NonEmptyVec::from(1));
+        let _ = all.slice(0..2);
+    }
+
+    #[test]
+    #[should_panic(expected = "maximum usize")]
+    fn test_slice_max_range() {
+        let all = NonEmptyArcSlice::from(NonEmptyVec::from(1));
+        let _ = all.slice(..=usize::MAX);
+    }
+
+    #[test]
+    fn test_empty_arc() {
+        let arc: Arc<[u32]> = Arc::from(Vec::new());
+        assert!(NonEmptyArcSlice::try_from(arc).is_err());
+    }
+
+    #[test]
+    fn test_fan_out() {
+        let data: NonEmptyVec<u64> = (1..=100).collect::<Vec<_>>().try_into().unwrap();
+        let data = NonEmptyArcSlice::from(data);
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let part = data.slice(i * 25..(i + 1) * 25).unwrap();
+                thread::spawn(move || part.iter().sum::<u64>())
+            })
+            .collect();
+        let total: u64 = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        assert_eq!(total, 5050);
+    }
+}
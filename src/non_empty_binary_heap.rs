@@ -0,0 +1,191 @@
+use {
+    crate::{unchecked, NonEmptyVec, NotEnoughElementsError},
+    std::{collections::BinaryHeap, convert::TryFrom, mem, num::NonZeroUsize},
+};
+
+/// a mostly costless wrapping of a binary heap, ensuring there's always
+/// at least one element, so that there's always a greatest one.
+#[derive(Debug, Clone)]
+pub struct NonEmptyBinaryHeap<T: Ord> {
+    heap: BinaryHeap<T>,
+}
+
+impl<T: Ord> NonEmptyBinaryHeap<T> {
+    #[inline]
+    pub fn new(first: T) -> Self {
+        let mut heap = BinaryHeap::new();
+        heap.push(first);
+        Self { heap }
+    }
+
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unchecked::non_zero(self.heap.len())
+    }
+
+    #[inline]
+    pub fn has_len(&self, len: usize) -> bool {
+        self.heap.len() == len
+    }
+
+    /// Return the greatest item
+    #[inline]
+    pub fn peek(&self) -> &T {
+        match self.heap.peek() {
+            Some(top) => top,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.heap.push(value);
+    }
+
+    /// Remove the greatest item and return it, or [`None`] if it's
+    /// the only one
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.heap.len() == 1 {
+            None
+        } else {
+            self.heap.pop()
+        }
+    }
+
+    /// Replace the greatest item with the given value, and return it.
+    ///
+    /// This is more efficient than a `pop` followed by a `push`, and
+    /// never fails (the heap may be of len 1).
+    ///
+    /// The inserted value becomes the new top only if it's greater than
+    /// all the other items.
+    pub fn pop_push(&mut self, value: T) -> T {
+        match self.heap.peek_mut() {
+            // the heap is sifted down when `top` is dropped
+            Some(mut top) => mem::replace(&mut *top, value),
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub fn iter(&self) -> std::collections::binary_heap::Iter<'_, T> {
+        self.heap.iter()
+    }
+
+    #[inline]
+    pub fn into_vec(self) -> NonEmptyVec<T> {
+        NonEmptyVec {
+            vec: self.heap.into_vec(),
+        }
+    }
+
+    /// Return the items, sorted in ascending order
+    #[inline]
+    pub fn into_sorted_non_empty_vec(self) -> NonEmptyVec<T> {
+        NonEmptyVec {
+            vec: self.heap.into_sorted_vec(),
+        }
+    }
+}
+
+impl<T: Ord> TryFrom<BinaryHeap<T>> for NonEmptyBinaryHeap<T> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(heap: BinaryHeap<T>) -> Result<Self, Self::Error> {
+        if heap.is_empty() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self { heap })
+        }
+    }
+}
+
+impl<T: Ord> TryFrom<Vec<T>> for NonEmptyBinaryHeap<T> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        if vec.is_empty() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self {
+                heap: BinaryHeap::from(vec),
+            })
+        }
+    }
+}
+
+impl<T: Ord> From<NonEmptyVec<T>> for NonEmptyBinaryHeap<T> {
+    #[inline]
+    fn from(vec: NonEmptyVec<T>) -> Self {
+        Self {
+            heap: BinaryHeap::from(vec.vec),
+        }
+    }
+}
+
+impl<T: Ord> From<T> for NonEmptyBinaryHeap<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a NonEmptyBinaryHeap<T> {
+    type Item = &'a T;
+    type IntoIter = std::collections::binary_heap::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.heap.iter()
+    }
+}
+
+#[cfg(test)]
+mod non_empty_binary_heap_tests {
+
+    use {super::*, std::cmp::Reverse};
+
+    #[test]
+    fn test_pop_push() {
+        let mut heap = NonEmptyBinaryHeap::new(3);
+        assert_eq!(*heap.peek(), 3);
+        assert_eq!(heap.pop(), None);
+        heap.push(5);
+        heap.push(1);
+        assert_eq!(heap.len().get(), 3);
+        assert_eq!(heap.pop(), Some(5));
+        assert_eq!(heap.pop(), Some(3));
+        assert_eq!(heap.pop(), None);
+        assert_eq!(*heap.peek(), 1);
+        assert_eq!(heap.pop_push(7), 1);
+        assert_eq!(*heap.peek(), 7);
+        assert!(heap.has_len(1));
+    }
+
+    #[test]
+    fn test_top_k() {
+        // keep the 3 greatest values in a min-heap
+        let mut heap: NonEmptyBinaryHeap<Reverse<u32>> =
+            vec![Reverse(0), Reverse(0), Reverse(0)].try_into().unwrap();
+        for v in [5, 1, 8, 3, 9, 2, 7] {
+            if v > heap.peek().0 {
+                heap.pop_push(Reverse(v));
+            }
+        }
+        let top: Vec<u32> = heap
+            .into_sorted_non_empty_vec()
+            .as_slice()
+            .iter()
+            .map(|r| r.0)
+            .collect();
+        assert_eq!(top, vec![9, 8, 7]);
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert!(NonEmptyBinaryHeap::<u8>::try_from(Vec::new()).is_err());
+        assert!(NonEmptyBinaryHeap::<u8>::try_from(BinaryHeap::new()).is_err());
+        let heap = NonEmptyBinaryHeap::<u8>::try_from(vec![2, 6, 4]).unwrap();
+        assert_eq!(heap.into_sorted_non_empty_vec().as_slice(), &[2, 4, 6]);
+    }
+}
@@ -0,0 +1,247 @@
+use {
+    crate::{unchecked, NonEmpty, NonEmptySlice, NonEmptyVec, NotEnoughElementsError},
+    std::{
+        convert::TryFrom,
+        fmt, hash,
+        num::NonZeroUsize,
+        ops::{Deref, Index, IndexMut},
+        slice, vec,
+    },
+};
+
+/// An owned non empty slice, with no spare capacity.
+///
+/// It's one word smaller than a [`NonEmptyVec`] and convenient for long
+/// lived immutable data.
+pub struct NonEmptyBoxedSlice<T> {
+    pub(crate) slice: Box<[T]>,
+}
+
+impl<T> NonEmptyBoxedSlice<T> {
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unchecked::non_zero(self.slice.len())
+    }
+
+    #[inline]
+    pub fn has_len(&self, len: usize) -> bool {
+        self.slice.len() == len
+    }
+
+    #[inline]
+    pub fn first(&self) -> &T {
+        unchecked::first(&self.slice)
+    }
+
+    #[inline]
+    pub fn first_mut(&mut self) -> &mut T {
+        unchecked::first_mut(&mut self.slice)
+    }
+
+    #[inline]
+    pub fn last(&self) -> &T {
+        unchecked::last(&self.slice)
+    }
+
+    #[inline]
+    pub fn last_mut(&mut self) -> &mut T {
+        unchecked::last_mut(&mut self.slice)
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.slice
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.slice
+    }
+
+    #[inline]
+    pub fn as_non_empty_slice(&self) -> NonEmptySlice<'_, T> {
+        NonEmptySlice { slice: &self.slice }
+    }
+
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.slice
+    }
+
+    /// Convert into a [`NonEmptyVec`], without reallocating
+    #[inline]
+    pub fn into_non_empty_vec(self) -> NonEmptyVec<T> {
+        NonEmptyVec {
+            vec: self.slice.into_vec(),
+        }
+    }
+}
+
+impl<T> TryFrom<Box<[T]>> for NonEmptyBoxedSlice<T> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(slice: Box<[T]>) -> Result<Self, Self::Error> {
+        if slice.is_empty() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self { slice })
+        }
+    }
+}
+
+/// Doesn't reallocate when the vec has no spare capacity
+impl<T> From<NonEmptyVec<T>> for NonEmptyBoxedSlice<T> {
+    #[inline]
+    fn from(vec: NonEmptyVec<T>) -> Self {
+        vec.into_boxed_slice()
+    }
+}
+
+impl<T> From<NonEmptyBoxedSlice<T>> for Box<[T]> {
+    #[inline]
+    fn from(slice: NonEmptyBoxedSlice<T>) -> Self {
+        slice.slice
+    }
+}
+
+impl<T: Clone> Clone for NonEmptyBoxedSlice<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for NonEmptyBoxedSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.slice.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for NonEmptyBoxedSlice<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.slice == other.slice
+    }
+}
+
+impl<T: Eq> Eq for NonEmptyBoxedSlice<T> {}
+
+impl<T: hash::Hash> hash::Hash for NonEmptyBoxedSlice<T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.slice.hash(state);
+    }
+}
+
+impl<T> Deref for NonEmptyBoxedSlice<T> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.slice
+    }
+}
+
+impl<T, I: slice::SliceIndex<[T]>> Index<I> for NonEmptyBoxedSlice<T> {
+    type Output = I::Output;
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(self.as_slice(), index)
+    }
+}
+
+impl<T, I: slice::SliceIndex<[T]>> IndexMut<I> for NonEmptyBoxedSlice<T> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(self.as_mut_slice(), index)
+    }
+}
+
+impl<T> IntoIterator for NonEmptyBoxedSlice<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> vec::IntoIter<T> {
+        self.slice.into_vec().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyBoxedSlice<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.slice.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut NonEmptyBoxedSlice<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+    #[inline]
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.slice.iter_mut()
+    }
+}
+
+impl<T> crate::non_empty::Sealed for NonEmptyBoxedSlice<T> {}
+
+impl<T> NonEmpty for NonEmptyBoxedSlice<T> {
+    type Item = T;
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        T: 'a;
+    #[inline]
+    fn len(&self) -> NonZeroUsize {
+        self.len()
+    }
+    #[inline]
+    fn first(&self) -> &T {
+        self.first()
+    }
+    #[inline]
+    fn last(&self) -> &T {
+        self.last()
+    }
+    #[inline]
+    fn iter(&self) -> slice::Iter<'_, T> {
+        self.slice.iter()
+    }
+}
+
+#[cfg(test)]
+mod non_empty_boxed_slice_tests {
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut vec = NonEmptyVec::with_capacity('a', 3);
+        vec.push('b');
+        vec.push('c');
+        let ptr = vec.as_ptr();
+        let mut slice = vec.into_boxed_slice();
+        assert_eq!(slice.as_ptr(), ptr);
+        assert_eq!(slice.len().get(), 3);
+        assert_eq!(*slice.first(), 'a');
+        *slice.last_mut() = 'z';
+        assert_eq!(slice.as_non_empty_slice().last(), &'z');
+        let vec = slice.into_non_empty_vec();
+        assert_eq!(vec.as_ptr(), ptr);
+        assert_eq!(vec, ['a', 'b', 'z']);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let boxed: Box<[u8]> = Box::new([]);
+        assert!(NonEmptyBoxedSlice::try_from(boxed).is_err());
+        let boxed: Box<[u8]> = Box::new([1, 2]);
+        let slice = NonEmptyBoxedSlice::try_from(boxed).unwrap();
+        assert!(slice.has_len(2));
+        assert_eq!(&slice[..], &[1, 2]);
+        let slice = NonEmptyBoxedSlice::from(NonEmptyVec::from(3));
+        assert_eq!(Box::<[u8]>::from(slice).as_ref(), &[3]);
+    }
+}
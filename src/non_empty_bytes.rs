@@ -0,0 +1,195 @@
+use {
+    crate::{unchecked, NonEmptySlice, NonEmptyVec, NotEnoughElementsError},
+    bytes::Bytes,
+    std::{
+        convert::TryFrom,
+        num::NonZeroUsize,
+        ops::{Deref, RangeBounds},
+    },
+};
+
+/// A wrapping of [`Bytes`], ensuring there's always at least one byte.
+///
+/// Cloning and slicing don't copy: the new value shares the same buffer.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyBytes {
+    bytes: Bytes,
+}
+
+impl NonEmptyBytes {
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unchecked::non_zero(self.bytes.len())
+    }
+
+    #[inline]
+    pub fn has_len(&self, len: usize) -> bool {
+        self.bytes.len() == len
+    }
+
+    #[inline]
+    pub fn first_byte(&self) -> u8 {
+        *unchecked::first(&self.bytes)
+    }
+
+    #[inline]
+    pub fn last_byte(&self) -> u8 {
+        *unchecked::last(&self.bytes)
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.bytes
+    }
+
+    #[inline]
+    pub fn as_non_empty_slice(&self) -> NonEmptySlice<'_, u8> {
+        NonEmptySlice { slice: &self.bytes }
+    }
+
+    /// Return a new value sharing the same buffer, restricted to the
+    /// given range.
+    ///
+    /// Fails if the range is empty, panics if it's out of bounds.
+    #[inline]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Result<Self, NotEnoughElementsError> {
+        Self::try_from(self.bytes.slice(range))
+    }
+
+    /// Split in two at the given index: `self` keeps `[at, len)` and the
+    /// returned value is `[0, at)`.
+    ///
+    /// Fails, leaving `self` untouched, if a half would be empty (i.e. if
+    /// `at` is 0 or `len`). Panics if `at > len`.
+    pub fn split_to(&mut self, at: usize) -> Result<Self, NotEnoughElementsError> {
+        assert!(at <= self.bytes.len(), "split_to out of bounds");
+        if at == 0 || at == self.bytes.len() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self {
+                bytes: self.bytes.split_to(at),
+            })
+        }
+    }
+
+    /// Split in two at the given index: `self` keeps `[0, at)` and the
+    /// returned value is `[at, len)`.
+    ///
+    /// Fails, leaving `self` untouched, if a half would be empty (i.e. if
+    /// `at` is 0 or `len`). Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> Result<Self, NotEnoughElementsError> {
+        assert!(at <= self.bytes.len(), "split_off out of bounds");
+        if at == 0 || at == self.bytes.len() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self {
+                bytes: self.bytes.split_off(at),
+            })
+        }
+    }
+
+    #[inline]
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
+impl TryFrom<Bytes> for NonEmptyBytes {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(bytes: Bytes) -> Result<Self, Self::Error> {
+        if bytes.is_empty() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self { bytes })
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for NonEmptyBytes {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(vec: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(Bytes::from(vec))
+    }
+}
+
+impl From<NonEmptyVec<u8>> for NonEmptyBytes {
+    #[inline]
+    fn from(vec: NonEmptyVec<u8>) -> Self {
+        Self {
+            bytes: Bytes::from(vec.vec),
+        }
+    }
+}
+
+/// Doesn't copy when the buffer isn't shared
+impl From<NonEmptyBytes> for NonEmptyVec<u8> {
+    #[inline]
+    fn from(bytes: NonEmptyBytes) -> Self {
+        NonEmptyVec {
+            vec: Vec::from(bytes.bytes),
+        }
+    }
+}
+
+impl From<NonEmptyBytes> for Bytes {
+    #[inline]
+    fn from(bytes: NonEmptyBytes) -> Self {
+        bytes.bytes
+    }
+}
+
+impl Deref for NonEmptyBytes {
+    type Target = [u8];
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+#[cfg(test)]
+mod non_empty_bytes_tests {
+
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        assert!(NonEmptyBytes::try_from(Bytes::new()).is_err());
+        assert!(NonEmptyBytes::try_from(Vec::new()).is_err());
+        let bytes = NonEmptyBytes::try_from(Bytes::from_static(b"frame")).unwrap();
+        assert_eq!(bytes.len().get(), 5);
+        assert_eq!(bytes.first_byte(), b'f');
+        assert_eq!(bytes.last_byte(), b'e');
+    }
+
+    #[test]
+    fn test_slice() {
+        let bytes = NonEmptyBytes::try_from(b"header:body".to_vec()).unwrap();
+        let body = bytes.slice(7..).unwrap();
+        assert_eq!(&*body, b"body");
+        assert_eq!(body.as_ptr(), bytes[7..].as_ptr());
+        assert!(bytes.slice(3..3).is_err());
+    }
+
+    #[test]
+    fn test_split() {
+        let mut bytes = NonEmptyBytes::try_from(b"abcd".to_vec()).unwrap();
+        assert!(bytes.split_to(0).is_err());
+        assert!(bytes.split_off(4).is_err());
+        assert!(bytes.has_len(4));
+        let head = bytes.split_to(1).unwrap();
+        assert_eq!(&*head, b"a");
+        let tail = bytes.split_off(2).unwrap();
+        assert_eq!(&*bytes, b"bc");
+        assert_eq!(&*tail, b"d");
+    }
+
+    #[test]
+    fn test_non_empty_vec_conversion() {
+        let vec: NonEmptyVec<u8> = vec![1, 2, 3].try_into().unwrap();
+        let bytes = NonEmptyBytes::from(vec);
+        let vec = NonEmptyVec::<u8>::from(bytes);
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+    }
+}
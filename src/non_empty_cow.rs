@@ -0,0 +1,161 @@
+use {
+    crate::{NonEmptySlice, NonEmptyVec},
+    std::{hash, ops::Deref},
+};
+
+/// A clone-on-write non empty sequence, either borrowing a [`NonEmptySlice`]
+/// or owning a [`NonEmptyVec`], like `Cow<[T]>`.
+///
+/// Equality and hashing only depend on the content, not on the variant.
+#[derive(Debug, Clone)]
+pub enum NonEmptyCow<'a, T: Clone> {
+    Borrowed(NonEmptySlice<'a, T>),
+    Owned(NonEmptyVec<T>),
+}
+
+impl<'a, T: Clone> NonEmptyCow<'a, T> {
+    #[inline]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    #[inline]
+    pub fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+
+    #[inline]
+    pub fn first(&self) -> &T {
+        match self {
+            Self::Borrowed(s) => s.first(),
+            Self::Owned(v) => v.first(),
+        }
+    }
+
+    #[inline]
+    pub fn last(&self) -> &T {
+        match self {
+            Self::Borrowed(s) => s.last(),
+            Self::Owned(v) => v.last(),
+        }
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        match self {
+            Self::Borrowed(s) => s.as_slice(),
+            Self::Owned(v) => v.as_slice(),
+        }
+    }
+
+    /// Return a mutable reference to the owned data, cloning the
+    /// borrowed slice on first call.
+    pub fn to_mut(&mut self) -> &mut NonEmptyVec<T> {
+        if let Self::Borrowed(s) = *self {
+            *self = Self::Owned(NonEmptyVec {
+                vec: s.as_slice().to_vec(),
+            });
+        }
+        match self {
+            Self::Owned(v) => v,
+            Self::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    /// Extract the owned data, cloning the borrowed slice if needed
+    pub fn into_owned(self) -> NonEmptyVec<T> {
+        match self {
+            Self::Borrowed(s) => NonEmptyVec {
+                vec: s.as_slice().to_vec(),
+            },
+            Self::Owned(v) => v,
+        }
+    }
+}
+
+impl<'a, T: Clone> Deref for NonEmptyCow<'a, T> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<'a, T: Clone> From<NonEmptySlice<'a, T>> for NonEmptyCow<'a, T> {
+    #[inline]
+    fn from(slice: NonEmptySlice<'a, T>) -> Self {
+        Self::Borrowed(slice)
+    }
+}
+
+impl<'a, T: Clone> From<NonEmptyVec<T>> for NonEmptyCow<'a, T> {
+    #[inline]
+    fn from(vec: NonEmptyVec<T>) -> Self {
+        Self::Owned(vec)
+    }
+}
+
+impl<'a, 'b, T: Clone + PartialEq> PartialEq<NonEmptyCow<'b, T>> for NonEmptyCow<'a, T> {
+    #[inline]
+    fn eq(&self, other: &NonEmptyCow<'b, T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<'a, T: Clone + Eq> Eq for NonEmptyCow<'a, T> {}
+
+impl<'a, T: Clone + hash::Hash> hash::Hash for NonEmptyCow<'a, T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod non_empty_cow_tests {
+
+    use {
+        super::*,
+        std::{
+            collections::hash_map::DefaultHasher,
+            convert::TryInto,
+            hash::{Hash, Hasher},
+        },
+    };
+
+    fn hash_of<H: Hash>(h: &H) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        h.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_to_mut() {
+        let data = [1, 2, 3];
+        let slice: NonEmptySlice<i32> = data[..].try_into().unwrap();
+        let mut cow = NonEmptyCow::from(slice);
+        assert!(cow.is_borrowed());
+        assert_eq!(*cow.first(), 1);
+        cow.to_mut().push(4);
+        assert!(cow.is_owned());
+        cow.to_mut().push(5);
+        assert_eq!(cow.as_slice(), &[1, 2, 3, 4, 5]);
+        assert_eq!(*cow.last(), 5);
+        assert_eq!(cow.into_owned().as_slice(), &[1, 2, 3, 4, 5]);
+        assert_eq!(data, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_mixed_variant_eq() {
+        let data = vec!["a".to_string(), "b".to_string()];
+        let slice: NonEmptySlice<String> = data[..].try_into().unwrap();
+        let borrowed = NonEmptyCow::from(slice);
+        let owned = NonEmptyCow::from(NonEmptyVec::try_from(data.clone()).unwrap());
+        assert_eq!(borrowed, owned);
+        assert_eq!(owned, borrowed);
+        assert_eq!(hash_of(&borrowed), hash_of(&owned));
+        let other = NonEmptyCow::from(NonEmptyVec::from("a".to_string()));
+        assert_ne!(borrowed, other);
+        assert_ne!(other, owned);
+    }
+}
@@ -0,0 +1,490 @@
+use {
+    crate::{
+        NonEmptySlice, NonEmptyVec, NotEnoughElementsError, OneOrTwo, OneOrTwoIter, OneToThree,
+        OneToThreeIter,
+    },
+    std::{cmp, convert::TryFrom, iter, slice, vec},
+};
+
+/// An iterator which is guaranteed to produce at least one item.
+///
+/// A `NonEmptyIterator` isn't an `Iterator`, so that its methods don't
+/// clash with the ones of `Iterator`, but it can always be converted into
+/// one with `into_iter`.
+///
+/// Adapters which can't change emptiness (`map`, `chain`, `zip`, `rev`,
+/// `enumerate`...) keep the guarantee while the ones which may remove
+/// items (`filter`, `skip`, `take`) return plain iterators.
+pub trait NonEmptyIterator: IntoIterator + Sized {
+    /// Return the first item and the iterator over the remaining ones
+    fn next_first(self) -> (Self::Item, Self::IntoIter);
+
+    #[inline]
+    fn first(self) -> Self::Item {
+        self.next_first().0
+    }
+
+    #[inline]
+    fn map<B, F>(self, f: F) -> NonEmptyIter<iter::Map<Self::IntoIter, F>>
+    where
+        F: FnMut(Self::Item) -> B,
+    {
+        NonEmptyIter::new(self.into_iter().map(f))
+    }
+
+    /// Chain with another iterator, which may be empty
+    #[inline]
+    fn chain<U>(self, other: U) -> NonEmptyIter<iter::Chain<Self::IntoIter, U::IntoIter>>
+    where
+        U: IntoIterator<Item = Self::Item>,
+    {
+        NonEmptyIter::new(self.into_iter().chain(other))
+    }
+
+    #[inline]
+    fn zip<U>(self, other: U) -> NonEmptyIter<iter::Zip<Self::IntoIter, U::IntoIter>>
+    where
+        U: NonEmptyIterator,
+    {
+        NonEmptyIter::new(self.into_iter().zip(other))
+    }
+
+    #[inline]
+    fn cloned<'a, T>(self) -> NonEmptyIter<iter::Cloned<Self::IntoIter>>
+    where
+        T: Clone + 'a,
+        Self::IntoIter: Iterator<Item = &'a T>,
+    {
+        NonEmptyIter::new(self.into_iter().cloned())
+    }
+
+    #[inline]
+    fn copied<'a, T>(self) -> NonEmptyIter<iter::Copied<Self::IntoIter>>
+    where
+        T: Copy + 'a,
+        Self::IntoIter: Iterator<Item = &'a T>,
+    {
+        NonEmptyIter::new(self.into_iter().copied())
+    }
+
+    #[inline]
+    fn enumerate(self) -> NonEmptyIter<iter::Enumerate<Self::IntoIter>> {
+        NonEmptyIter::new(self.into_iter().enumerate())
+    }
+
+    #[inline]
+    fn rev(self) -> NonEmptyIter<iter::Rev<Self::IntoIter>>
+    where
+        Self::IntoIter: DoubleEndedIterator,
+    {
+        NonEmptyIter::new(self.into_iter().rev())
+    }
+
+    /// Collect the items into a [`NonEmptyVec`]
+    #[inline]
+    fn collect_vec(self) -> NonEmptyVec<Self::Item> {
+        let (first, rest) = self.next_first();
+        NonEmptyVec::from_first_and_rest(first, rest)
+    }
+
+    /// Return the greatest item (the last one if several are equally maximum)
+    #[inline]
+    fn max(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        let (first, rest) = self.next_first();
+        rest.fold(first, cmp::max)
+    }
+
+    /// Return the smallest item (the first one if several are equally minimum)
+    #[inline]
+    fn min(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        let (first, rest) = self.next_first();
+        rest.fold(first, cmp::min)
+    }
+
+    /// Return the item with the greatest key (the last one if several
+    /// are equally maximum)
+    #[inline]
+    fn max_by_key<B, F>(self, f: F) -> Self::Item
+    where
+        B: Ord,
+        F: FnMut(&Self::Item) -> B,
+    {
+        match self.into_iter().max_by_key(f) {
+            Some(item) => item,
+            None => unreachable!(),
+        }
+    }
+
+    /// Return the item with the smallest key (the first one if several
+    /// are equally minimum)
+    #[inline]
+    fn min_by_key<B, F>(self, f: F) -> Self::Item
+    where
+        B: Ord,
+        F: FnMut(&Self::Item) -> B,
+    {
+        match self.into_iter().min_by_key(f) {
+            Some(item) => item,
+            None => unreachable!(),
+        }
+    }
+
+    /// Return the greatest item according to `compare` (the last one if
+    /// several are equally maximum)
+    #[inline]
+    fn max_by<F>(self, compare: F) -> Self::Item
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> cmp::Ordering,
+    {
+        match self.into_iter().max_by(compare) {
+            Some(item) => item,
+            None => unreachable!(),
+        }
+    }
+
+    /// Return the smallest item according to `compare` (the first one if
+    /// several are equally minimum)
+    #[inline]
+    fn min_by<F>(self, compare: F) -> Self::Item
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> cmp::Ordering,
+    {
+        match self.into_iter().min_by(compare) {
+            Some(item) => item,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn last(self) -> Self::Item {
+        let (first, rest) = self.next_first();
+        rest.last().unwrap_or(first)
+    }
+
+    /// Reduce the items to one by repeatedly applying `f`, from left to right
+    #[inline]
+    fn reduce<F>(self, f: F) -> Self::Item
+    where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        let (first, rest) = self.next_first();
+        rest.fold(first, f)
+    }
+
+    #[inline]
+    fn filter<P>(self, predicate: P) -> iter::Filter<Self::IntoIter, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.into_iter().filter(predicate)
+    }
+
+    #[inline]
+    fn skip(self, n: usize) -> iter::Skip<Self::IntoIter> {
+        self.into_iter().skip(n)
+    }
+
+    #[inline]
+    fn take(self, n: usize) -> iter::Take<Self::IntoIter> {
+        self.into_iter().take(n)
+    }
+}
+
+/// Conversion into a [`NonEmptyIterator`], the non empty counterpart
+/// of `IntoIterator`.
+///
+/// It's implemented by all non empty iterators, by the crate's non empty
+/// collections and by references to them.
+pub trait IntoNonEmptyIterator: Sized {
+    type Item;
+    type IntoNonEmptyIter: NonEmptyIterator<Item = Self::Item>;
+
+    fn into_non_empty_iter(self) -> Self::IntoNonEmptyIter;
+
+    /// Collect the items into a non empty collection
+    #[inline]
+    fn collect_non_empty<C>(self) -> C
+    where
+        C: FromNonEmptyIterator<Self::Item>,
+    {
+        C::from_non_empty_iter(self)
+    }
+}
+
+impl<I: NonEmptyIterator> IntoNonEmptyIterator for I {
+    type Item = I::Item;
+    type IntoNonEmptyIter = I;
+    #[inline]
+    fn into_non_empty_iter(self) -> I {
+        self
+    }
+}
+
+macro_rules! impl_into_non_empty_iterator {
+    ([$($gen:tt)*] $type:ty, $iter:ty, |$c:ident| $make:expr) => {
+        impl<$($gen)*> IntoNonEmptyIterator for $type {
+            type Item = <$iter as Iterator>::Item;
+            type IntoNonEmptyIter = NonEmptyIter<$iter>;
+            #[inline]
+            fn into_non_empty_iter(self) -> Self::IntoNonEmptyIter {
+                let $c = self;
+                NonEmptyIter::new($make)
+            }
+        }
+    };
+}
+
+impl_into_non_empty_iterator!([T] NonEmptyVec<T>, vec::IntoIter<T>, |c| c.vec.into_iter());
+impl_into_non_empty_iterator!(['a, T] &'a NonEmptyVec<T>, slice::Iter<'a, T>, |c| c.vec.iter());
+impl_into_non_empty_iterator!(
+    ['a, T] &'a mut NonEmptyVec<T>,
+    slice::IterMut<'a, T>,
+    |c| c.vec.iter_mut()
+);
+impl_into_non_empty_iterator!(['a, T] NonEmptySlice<'a, T>, slice::Iter<'a, T>, |c| c.slice.iter());
+impl_into_non_empty_iterator!(['a, T] &NonEmptySlice<'a, T>, slice::Iter<'a, T>, |c| c.slice.iter());
+impl_into_non_empty_iterator!([T] OneToThree<T>, vec::IntoIter<T>, |c| c.to_vec().into_iter());
+impl_into_non_empty_iterator!(['a, T] &'a OneToThree<T>, OneToThreeIter<'a, T>, |c| c.iter());
+impl_into_non_empty_iterator!([T] OneOrTwo<T>, vec::IntoIter<T>, |c| c.to_vec().into_iter());
+impl_into_non_empty_iterator!(['a, T] &'a OneOrTwo<T>, OneOrTwoIter<'a, T>, |c| c.iter());
+
+/// A collection which can be built from a [`NonEmptyIterator`]
+pub trait FromNonEmptyIterator<T>: Sized {
+    fn from_non_empty_iter<I>(iter: I) -> Self
+    where
+        I: IntoNonEmptyIterator<Item = T>;
+}
+
+/// The first item is taken with `next_first`, so that an implementation
+/// of [`NonEmptyIterator`] which doesn't honor its contract can't make
+/// an empty vec.
+impl<T> FromNonEmptyIterator<T> for NonEmptyVec<T> {
+    #[inline]
+    fn from_non_empty_iter<I>(iter: I) -> Self
+    where
+        I: IntoNonEmptyIterator<Item = T>,
+    {
+        let (first, rest) = iter.into_non_empty_iter().next_first();
+        Self::from_first_and_rest(first, rest)
+    }
+}
+
+/// Collection of any iterator into a [`NonEmptyVec`], when it's not
+/// known to produce at least one item.
+///
+/// It's implemented for all iterators.
+pub trait CollectNonEmpty: Iterator + Sized {
+    /// Collect the items, failing if there's none
+    #[inline]
+    fn try_collect_non_empty(self) -> Result<NonEmptyVec<Self::Item>, NotEnoughElementsError> {
+        NonEmptyVec::try_from(self.collect::<Vec<_>>())
+    }
+
+    /// Collect the items, or return a vec of just `fallback` if there's none
+    #[inline]
+    fn collect_non_empty_or(self, fallback: Self::Item) -> NonEmptyVec<Self::Item> {
+        self.collect_non_empty_or_else(|| fallback)
+    }
+
+    /// Collect the items, or return a vec of just the result of `fallback`
+    /// (only called if there's no item)
+    #[inline]
+    fn collect_non_empty_or_else<F>(mut self, fallback: F) -> NonEmptyVec<Self::Item>
+    where
+        F: FnOnce() -> Self::Item,
+    {
+        match self.next() {
+            Some(first) => NonEmptyVec::from_first_and_rest(first, self),
+            None => NonEmptyVec::new(fallback()),
+        }
+    }
+}
+
+impl<I: Iterator> CollectNonEmpty for I {}
+
+/// The wrapping of an iterator known to produce at least one item.
+///
+/// It's obtained with the `iter_ne` methods of the crate's collections
+/// and with the adapters of [`NonEmptyIterator`].
+#[derive(Debug, Clone)]
+pub struct NonEmptyIter<I> {
+    iter: I,
+}
+
+impl<I: Iterator> NonEmptyIter<I> {
+    /// The caller must ensure the iterator isn't empty
+    #[inline]
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I: Iterator> IntoIterator for NonEmptyIter<I> {
+    type Item = I::Item;
+    type IntoIter = I;
+    #[inline]
+    fn into_iter(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Iterator> NonEmptyIterator for NonEmptyIter<I> {
+    #[inline]
+    fn next_first(mut self) -> (I::Item, I) {
+        match self.iter.next() {
+            Some(first) => (first, self.iter),
+            None => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod non_empty_iter_tests {
+
+    use {
+        super::*,
+        std::convert::{TryFrom, TryInto},
+    };
+
+    #[test]
+    fn test_adapters() {
+        let vec: NonEmptyVec<u32> = vec![3, 1, 4].try_into().unwrap();
+        let doubled: NonEmptyVec<u32> = vec.iter_ne().map(|v| v * 2).collect_non_empty();
+        assert_eq!(doubled.as_slice(), &[6, 2, 8]);
+        let few = OneToThree::two(10, 20);
+        let chained: NonEmptyVec<u32> = few
+            .iter_ne()
+            .chain(doubled.as_slice())
+            .map(|&v| v)
+            .collect_non_empty();
+        assert_eq!(chained.as_slice(), &[10, 20, 6, 2, 8]);
+        let zipped: NonEmptyVec<(&u32, &u32)> =
+            vec.iter_ne().zip(few.iter_ne()).collect_non_empty();
+        assert_eq!(zipped.as_slice(), &[(&3, &10), (&1, &20)]);
+    }
+
+    #[test]
+    fn test_length_preserving_adapters() {
+        let vec: NonEmptyVec<u32> = vec![3, 1, 4].try_into().unwrap();
+        let indexed = vec
+            .iter_ne()
+            .copied()
+            .rev()
+            .enumerate()
+            .map(|(i, v)| i as u32 * 10 + v)
+            .collect_vec();
+        assert_eq!(indexed.as_slice(), &[4, 11, 23]);
+        let names: NonEmptyVec<String> = vec!["a".to_string()].try_into().unwrap();
+        let slice = NonEmptySlice::from(&names);
+        let pairs = slice
+            .iter_ne()
+            .cloned()
+            .zip(vec.iter_ne().rev())
+            .collect_vec();
+        assert_eq!(pairs.as_slice(), &[("a".to_string(), &4)]);
+    }
+
+    #[test]
+    fn test_reductions() {
+        let vec: NonEmptyVec<i32> = vec![3, -1, 4, -1].try_into().unwrap();
+        assert_eq!(*vec.iter_ne().max(), 4);
+        assert_eq!(*vec.iter_ne().min(), -1);
+        assert_eq!(*vec.iter_ne().first(), 3);
+        assert_eq!(*vec.iter_ne().last(), -1);
+        assert_eq!(vec.iter_ne().map(|&v| v).reduce(|a, b| a - b), 1);
+        let slice: NonEmptySlice<i32> = vec.as_slice()[2..].try_into().unwrap();
+        assert_eq!(*slice.iter_ne().max(), 4);
+        let one = NonEmptyVec::from("alone");
+        assert_eq!(one.into_iter_ne().reduce(|a, _| a), "alone");
+    }
+
+    #[test]
+    fn test_into_non_empty_iter() {
+        let few = OneToThree::three(1u32, 2, 3);
+        let vec = NonEmptyVec::from_non_empty_iter(few);
+        let squares: NonEmptyVec<u32> =
+            vec.into_non_empty_iter().map(|v| v * v).collect_non_empty();
+        assert_eq!(squares.as_slice(), &[1, 4, 9]);
+        let refs: NonEmptyVec<&u32> = (&squares).collect_non_empty();
+        assert_eq!(*refs.iter_ne().max(), &9);
+        let slice = NonEmptySlice::try_from(&squares[1..]).unwrap();
+        assert_eq!(
+            NonEmptyVec::from_non_empty_iter(slice).as_slice(),
+            &[&4, &9]
+        );
+        let pair: NonEmptyVec<char> = OneOrTwo::two('a', 'b').collect_non_empty();
+        assert!(pair.has_len(2));
+        assert_eq!(sum(&OneToThree::two(4, 5)), 9);
+        assert_eq!(sum(&squares), 14);
+    }
+
+    fn sum<'a, I: IntoNonEmptyIterator<Item = &'a u32>>(iter: I) -> u32 {
+        iter.into_non_empty_iter().map(|&v| v).reduce(|a, b| a + b)
+    }
+
+    #[test]
+    fn test_collect_non_empty() {
+        assert!(iter::empty::<u8>().try_collect_non_empty().is_err());
+        let vec = iter::once("a").try_collect_non_empty().unwrap();
+        assert_eq!(vec.as_slice(), &["a"]);
+        let vec = (1..4)
+            .filter(|v| v % 2 == 1)
+            .try_collect_non_empty()
+            .unwrap();
+        assert_eq!(vec.as_slice(), &[1, 3]);
+        assert_eq!(iter::empty().collect_non_empty_or(0).as_slice(), &[0]);
+        let vec = (5..7).collect_non_empty_or_else(|| panic!("not lazy"));
+        assert_eq!(vec.as_slice(), &[5, 6]);
+        let vec = (5..5).collect_non_empty_or_else(|| 9);
+        assert_eq!(vec.as_slice(), &[9]);
+    }
+
+    /// A broken implementation, claiming to be non empty while it isn't
+    struct Liar;
+
+    impl IntoIterator for Liar {
+        type Item = u8;
+        type IntoIter = iter::Empty<u8>;
+        fn into_iter(self) -> iter::Empty<u8> {
+            iter::empty()
+        }
+    }
+
+    impl NonEmptyIterator for Liar {
+        fn next_first(self) -> (u8, iter::Empty<u8>) {
+            panic!("no first item")
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "no first item")]
+    fn test_lying_iterator_collect_non_empty() {
+        let _: NonEmptyVec<u8> = Liar.collect_non_empty();
+    }
+
+    #[test]
+    #[should_panic(expected = "no first item")]
+    fn test_lying_iterator_collect_vec() {
+        let _ = Liar.collect_vec();
+    }
+
+    #[test]
+    #[should_panic(expected = "no first item")]
+    fn test_lying_iterator_from_non_empty_iter() {
+        let _ = NonEmptyVec::from_non_empty_iter(Liar);
+    }
+
+    #[test]
+    fn test_shrinking_adapters() {
+        let vec: NonEmptyVec<u8> = vec![1, 2, 3].try_into().unwrap();
+        assert_eq!(vec.iter_ne().filter(|&&v| v > 5).count(), 0);
+        assert_eq!(vec.iter_ne().skip(2).count(), 1);
+        assert_eq!(vec.iter_ne().take(2).count(), 2);
+    }
+}
@@ -0,0 +1,237 @@
+use {
+    crate::{NonEmptyVec, NotEnoughElementsError},
+    std::{
+        convert::TryFrom,
+        ffi::OsStr,
+        ops::Deref,
+        path::{Component, Path, PathBuf},
+    },
+};
+
+fn has_components(path: &Path) -> bool {
+    path.components().next().is_some()
+}
+
+fn last_component(path: &Path) -> Component<'_> {
+    match path.components().next_back() {
+        Some(c) => c,
+        None => unreachable!(),
+    }
+}
+
+fn components(path: &Path) -> NonEmptyVec<Component<'_>> {
+    NonEmptyVec {
+        vec: path.components().collect(),
+    }
+}
+
+/// A borrowed path with at least one component (so not `""`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyPath<'a> {
+    path: &'a Path,
+}
+
+impl<'a> NonEmptyPath<'a> {
+    #[inline]
+    pub fn as_path(&self) -> &'a Path {
+        self.path
+    }
+
+    #[inline]
+    pub fn last_component(&self) -> Component<'a> {
+        last_component(self.path)
+    }
+
+    /// Return the file name, or the last component when there's no file
+    /// name (e.g. for `/` or `..`)
+    #[inline]
+    pub fn file_name_or_root(&self) -> &'a OsStr {
+        match self.path.file_name() {
+            Some(name) => name,
+            None => self.last_component().as_os_str(),
+        }
+    }
+
+    #[inline]
+    pub fn components_non_empty(&self) -> NonEmptyVec<Component<'a>> {
+        components(self.path)
+    }
+
+    #[inline]
+    pub fn to_non_empty_path_buf(&self) -> NonEmptyPathBuf {
+        NonEmptyPathBuf {
+            path: self.path.to_path_buf(),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Path> for NonEmptyPath<'a> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(path: &'a Path) -> Result<Self, Self::Error> {
+        if has_components(path) {
+            Ok(Self { path })
+        } else {
+            Err(NotEnoughElementsError)
+        }
+    }
+}
+
+impl Deref for NonEmptyPath<'_> {
+    type Target = Path;
+    #[inline]
+    fn deref(&self) -> &Path {
+        self.path
+    }
+}
+
+impl AsRef<Path> for NonEmptyPath<'_> {
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        self.path
+    }
+}
+
+/// An owned path with at least one component (so not `""`)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyPathBuf {
+    path: PathBuf,
+}
+
+impl NonEmptyPathBuf {
+    #[inline]
+    pub fn as_path(&self) -> &Path {
+        &self.path
+    }
+
+    #[inline]
+    pub fn as_non_empty_path(&self) -> NonEmptyPath<'_> {
+        NonEmptyPath { path: &self.path }
+    }
+
+    #[inline]
+    pub fn last_component(&self) -> Component<'_> {
+        last_component(&self.path)
+    }
+
+    /// Return the file name, or the last component when there's no file
+    /// name (e.g. for `/` or `..`)
+    #[inline]
+    pub fn file_name_or_root(&self) -> &OsStr {
+        self.as_non_empty_path().file_name_or_root()
+    }
+
+    #[inline]
+    pub fn components_non_empty(&self) -> NonEmptyVec<Component<'_>> {
+        components(&self.path)
+    }
+
+    /// Extend the path, as does [`PathBuf::push`]
+    #[inline]
+    pub fn push<P: AsRef<Path>>(&mut self, path: P) {
+        self.path.push(path);
+    }
+
+    /// Truncate the path to its parent, unless the parent is empty.
+    ///
+    /// Return whether the path was truncated.
+    pub fn pop(&mut self) -> bool {
+        match self.path.parent() {
+            Some(parent) if has_components(parent) => self.path.pop(),
+            _ => false,
+        }
+    }
+
+    #[inline]
+    pub fn into_path_buf(self) -> PathBuf {
+        self.path
+    }
+}
+
+impl TryFrom<PathBuf> for NonEmptyPathBuf {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        if has_components(&path) {
+            Ok(Self { path })
+        } else {
+            Err(NotEnoughElementsError)
+        }
+    }
+}
+
+impl TryFrom<&Path> for NonEmptyPathBuf {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        NonEmptyPath::try_from(path).map(|p| p.to_non_empty_path_buf())
+    }
+}
+
+impl From<NonEmptyPathBuf> for PathBuf {
+    #[inline]
+    fn from(p: NonEmptyPathBuf) -> Self {
+        p.path
+    }
+}
+
+impl Deref for NonEmptyPathBuf {
+    type Target = Path;
+    #[inline]
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AsRef<Path> for NonEmptyPathBuf {
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod non_empty_path_tests {
+
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        assert!(NonEmptyPath::try_from(Path::new("")).is_err());
+        assert!(NonEmptyPathBuf::try_from(PathBuf::new()).is_err());
+        assert!(NonEmptyPath::try_from(Path::new(".")).is_ok());
+    }
+
+    #[test]
+    fn test_accessors() {
+        let path = NonEmptyPath::try_from(Path::new("/usr/lib/")).unwrap();
+        assert_eq!(path.file_name_or_root(), "lib");
+        assert_eq!(
+            path.components_non_empty().as_slice(),
+            &[
+                Component::RootDir,
+                Component::Normal("usr".as_ref()),
+                Component::Normal("lib".as_ref()),
+            ],
+        );
+        let root = NonEmptyPath::try_from(Path::new("/")).unwrap();
+        assert_eq!(root.file_name_or_root(), "/");
+        let up = NonEmptyPathBuf::try_from(Path::new("../..")).unwrap();
+        assert_eq!(up.file_name_or_root(), "..");
+        assert_eq!(up.last_component(), Component::ParentDir);
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut path = NonEmptyPathBuf::try_from(PathBuf::from("a")).unwrap();
+        path.push("b");
+        path.push("");
+        assert_eq!(path.components_non_empty().len().get(), 2);
+        assert!(path.pop());
+        assert_eq!(path.as_path(), Path::new("a"));
+        assert!(!path.pop());
+        assert_eq!(path.as_path(), Path::new("a"));
+        let mut root = NonEmptyPathBuf::try_from(PathBuf::from("/")).unwrap();
+        assert!(!root.pop());
+    }
+}
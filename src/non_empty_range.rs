@@ -0,0 +1,285 @@
+use {
+    crate::{NonEmptySlice, NonEmptyVec, NotEnoughElementsError},
+    std::{
+        cmp,
+        convert::TryFrom,
+        num::NonZeroUsize,
+        ops::{Range, RangeInclusive},
+    },
+};
+
+/// A range of integers containing at least one value.
+///
+/// **Both bounds are inclusive**: `start <= end` is always true and
+/// `end` is part of the range, as in `start..=end`.
+///
+/// A `Range` (exclusive) can be converted too, as long as it's not empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonEmptyRange<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Copy + Ord> NonEmptyRange<T> {
+    /// the first value of the range
+    #[inline]
+    pub fn first(&self) -> T {
+        self.start
+    }
+
+    /// the last value of the range, included
+    #[inline]
+    pub fn last(&self) -> T {
+        self.end
+    }
+
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        self.start <= *value && *value <= self.end
+    }
+
+    #[inline]
+    pub fn to_range_inclusive(&self) -> RangeInclusive<T> {
+        self.start..=self.end
+    }
+
+    /// Return the range of the values which are in both ranges, if any
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let start = cmp::max(self.start, other.start);
+        let end = cmp::min(self.end, other.end);
+        if start <= end {
+            Some(Self { start, end })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> NonEmptyRange<T>
+where
+    RangeInclusive<T>: Iterator<Item = T>,
+    T: Copy,
+{
+    #[inline]
+    pub fn iter(&self) -> RangeInclusive<T> {
+        self.start..=self.end
+    }
+}
+
+impl<T> From<T> for NonEmptyRange<T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn from(value: T) -> Self {
+        Self {
+            start: value,
+            end: value,
+        }
+    }
+}
+
+impl<T: Copy + Ord> TryFrom<RangeInclusive<T>> for NonEmptyRange<T> {
+    type Error = NotEnoughElementsError;
+    /// Fail when `start > end`. The exhaustion state of an already
+    /// iterated `RangeInclusive` is ignored.
+    #[inline]
+    fn try_from(range: RangeInclusive<T>) -> Result<Self, Self::Error> {
+        let (start, end) = range.into_inner();
+        if start <= end {
+            Ok(Self { start, end })
+        } else {
+            Err(NotEnoughElementsError)
+        }
+    }
+}
+
+macro_rules! impl_integer_range {
+    ($($t:ty),*) => {
+        $(
+            impl NonEmptyRange<$t> {
+                /// Return the number of values in the range.
+                ///
+                /// Panics if this number doesn't fit in a usize, which can only
+                /// happen for ranges covering most of a wide type.
+                #[inline]
+                pub fn len(&self) -> NonZeroUsize {
+                    self.checked_len()
+                        .expect("range length overflows usize")
+                }
+
+                /// Return the number of values in the range, or `None` if it
+                /// doesn't fit in a usize
+                #[inline]
+                pub fn checked_len(&self) -> Option<NonZeroUsize> {
+                    let diff = (self.end as i128) - (self.start as i128);
+                    usize::try_from(diff)
+                        .ok()
+                        .and_then(|d| d.checked_add(1))
+                        .and_then(NonZeroUsize::new)
+                }
+
+                /// Return the range covering both ranges when they overlap
+                /// or are contiguous, `None` when there's a gap between them
+                pub fn union(&self, other: &Self) -> Option<Self> {
+                    let (a, b) = if self.start <= other.start {
+                        (self, other)
+                    } else {
+                        (other, self)
+                    };
+                    if a.end >= b.start || a.end + 1 == b.start {
+                        Some(Self {
+                            start: a.start,
+                            end: cmp::max(a.end, b.end),
+                        })
+                    } else {
+                        None
+                    }
+                }
+
+                /// Split the range in `start..mid` and `mid..=end`.
+                ///
+                /// Fail when one of the parts would be empty, that is when
+                /// `mid <= start` or `mid > end`.
+                pub fn split_at(&self, mid: $t) -> Result<(Self, Self), NotEnoughElementsError> {
+                    if mid <= self.start || mid > self.end {
+                        Err(NotEnoughElementsError)
+                    } else {
+                        Ok((
+                            Self { start: self.start, end: mid - 1 },
+                            Self { start: mid, end: self.end },
+                        ))
+                    }
+                }
+            }
+
+            impl TryFrom<Range<$t>> for NonEmptyRange<$t> {
+                type Error = NotEnoughElementsError;
+                #[inline]
+                fn try_from(range: Range<$t>) -> Result<Self, Self::Error> {
+                    if range.start < range.end {
+                        Ok(Self { start: range.start, end: range.end - 1 })
+                    } else {
+                        Err(NotEnoughElementsError)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_range!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl<'a, T> NonEmptySlice<'a, T> {
+    /// Return the non empty subslice covered by the range, or `None`
+    /// if it's out of bounds
+    #[inline]
+    pub fn get_range(&self, range: NonEmptyRange<usize>) -> Option<NonEmptySlice<'a, T>> {
+        self.slice
+            .get(range.start..=range.end)
+            .map(|slice| NonEmptySlice { slice })
+    }
+
+    /// Return the non empty subslice covered by the range.
+    ///
+    /// Panics if the range is out of bounds, like slice indexing.
+    #[inline]
+    pub fn index_range(&self, range: NonEmptyRange<usize>) -> NonEmptySlice<'a, T> {
+        NonEmptySlice {
+            slice: &self.slice[range.start..=range.end],
+        }
+    }
+}
+
+impl<T> NonEmptyVec<T> {
+    /// Return the non empty subslice covered by the range, or `None`
+    /// if it's out of bounds
+    #[inline]
+    pub fn get_range(&self, range: NonEmptyRange<usize>) -> Option<NonEmptySlice<'_, T>> {
+        self.vec
+            .get(range.start..=range.end)
+            .map(|slice| NonEmptySlice { slice })
+    }
+
+    /// Return the non empty subslice covered by the range.
+    ///
+    /// Panics if the range is out of bounds, like slice indexing.
+    #[inline]
+    pub fn index_range(&self, range: NonEmptyRange<usize>) -> NonEmptySlice<'_, T> {
+        NonEmptySlice {
+            slice: &self.vec[range.start..=range.end],
+        }
+    }
+}
+
+#[cfg(test)]
+mod non_empty_range_tests {
+
+    use {super::*, std::convert::TryInto};
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_conversions() {
+        let r: NonEmptyRange<usize> = (2..5).try_into().unwrap();
+        assert_eq!(r.first(), 2);
+        assert_eq!(r.last(), 4);
+        assert_eq!(r.len().get(), 3);
+        assert_eq!(r, (2..=4).try_into().unwrap());
+        assert!(NonEmptyRange::<usize>::try_from(3..3).is_err());
+        assert!(NonEmptyRange::<i32>::try_from(3..=2).is_err());
+        let one: NonEmptyRange<i8> = (-3..=-3).try_into().unwrap();
+        assert_eq!(one.len().get(), 1);
+        assert_eq!(NonEmptyRange::from(-3i8), one);
+        let full: NonEmptyRange<u64> = (0..=u64::MAX).try_into().unwrap();
+        assert!(full.checked_len().is_none());
+        let full: NonEmptyRange<i8> = (i8::MIN..=i8::MAX).try_into().unwrap();
+        assert_eq!(full.len().get(), 256);
+    }
+
+    #[test]
+    fn test_iter_contains() {
+        let r: NonEmptyRange<i32> = (-1..2).try_into().unwrap();
+        assert_eq!(r.iter().collect::<Vec<_>>(), vec![-1, 0, 1]);
+        assert!(r.contains(&-1));
+        assert!(r.contains(&1));
+        assert!(!r.contains(&2));
+    }
+
+    #[test]
+    fn test_intersect_union() {
+        let a: NonEmptyRange<u32> = (1..=5).try_into().unwrap();
+        let b: NonEmptyRange<u32> = (5..=9).try_into().unwrap();
+        let c: NonEmptyRange<u32> = (6..=7).try_into().unwrap();
+        assert_eq!(a.intersect(&b), Some(NonEmptyRange::from(5)));
+        assert_eq!(a.intersect(&c), None);
+        assert_eq!(a.union(&b), Some((1..=9).try_into().unwrap()));
+        assert_eq!(c.union(&a), Some((1..=7).try_into().unwrap())); // contiguous
+        let d: NonEmptyRange<u32> = (8..=9).try_into().unwrap();
+        assert_eq!(a.union(&d), None);
+    }
+
+    #[test]
+    fn test_split_at() {
+        let r: NonEmptyRange<u16> = (3..=6).try_into().unwrap();
+        let (a, b) = r.split_at(4).unwrap();
+        assert_eq!(a, NonEmptyRange::from(3));
+        assert_eq!(b, (4..=6).try_into().unwrap());
+        let (a, b) = r.split_at(6).unwrap();
+        assert_eq!(a, (3..=5).try_into().unwrap());
+        assert_eq!(b, NonEmptyRange::from(6));
+        assert!(r.split_at(3).is_err());
+        assert!(r.split_at(7).is_err());
+    }
+
+    #[test]
+    fn test_index_vec() {
+        let vec: NonEmptyVec<char> = vec!['a', 'b', 'c', 'd'].try_into().unwrap();
+        let slice = vec.index_range((1..3).try_into().unwrap());
+        assert_eq!(slice.as_slice(), &['b', 'c']);
+        assert_eq!(*slice.first(), 'b');
+        let sub = slice.index_range(NonEmptyRange::from(1));
+        assert_eq!(sub.as_slice(), &['c']);
+        assert!(vec.get_range((3..5).try_into().unwrap()).is_none());
+        assert!(slice.get_range(NonEmptyRange::from(2)).is_none());
+    }
+}
@@ -0,0 +1,995 @@
+use {
+    crate::{
+        non_empty_vec::join_non_empty, unchecked, NonEmpty, NonEmptyIter, NonEmptyIterator,
+        NonEmptyVec, NotEnoughElementsError,
+    },
+    std::{
+        array,
+        cmp::Ordering,
+        convert::{TryFrom, TryInto},
+        fmt,
+        iter::FusedIterator,
+        num::NonZeroUsize,
+        ops::{Bound, Deref, Index, RangeBounds},
+        slice,
+    },
+};
+
+/// a slice with at least one element.
+///
+/// This is the borrowed counterpart of [`crate::NonEmptyVec`], and it's
+/// `Copy`, like `&[T]`.
+#[derive(Debug)]
+pub struct NonEmptySlice<'a, T> {
+    pub(crate) slice: &'a [T],
+}
+
+/// Build a `NonEmptySlice<'static, T>` from literal elements, checked at
+/// compile time, usable in a `const` or a `static`:
+///
+/// ```
+/// use strict::{nes, NonEmptySlice};
+/// static UNITS: NonEmptySlice<&str> = nes!["B", "KB", "MB", "GB"];
+/// assert_eq!(*UNITS.first(), "B");
+/// assert_eq!(UNITS.len().get(), 4);
+/// ```
+///
+/// Giving no element doesn't compile:
+///
+/// ```compile_fail
+/// let slice: strict::NonEmptySlice<u8> = strict::nes![];
+/// ```
+#[macro_export]
+macro_rules! nes {
+    () => {
+        compile_error!("a NonEmptySlice needs at least one element")
+    };
+    ($($elem:expr),+ $(,)?) => {
+        $crate::NonEmptySlice::from_array(&[$($elem),+])
+    };
+}
+
+pub(crate) struct AssertNonEmpty<const N: usize>;
+impl<const N: usize> AssertNonEmpty<N> {
+    pub(crate) const OK: () = assert!(N > 0, "empty array");
+}
+
+impl<'a, T> NonEmptySlice<'a, T> {
+    /// Build a non empty slice from a reference to an array, checking
+    /// at compile time that the array isn't empty.
+    ///
+    /// ```
+    /// use strict::NonEmptySlice;
+    /// static DIGITS: NonEmptySlice<u8> = NonEmptySlice::from_array(&[0, 1, 2]);
+    /// assert_eq!(DIGITS.len().get(), 3);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use strict::NonEmptySlice;
+    /// static NONE: NonEmptySlice<u8> = NonEmptySlice::from_array(&[]);
+    /// ```
+    #[inline]
+    #[allow(clippy::let_unit_value)]
+    pub const fn from_array<const N: usize>(array: &'a [T; N]) -> Self {
+        let _ = AssertNonEmpty::<N>::OK;
+        Self { slice: array }
+    }
+
+    /// Wrap the slice if it's not empty.
+    ///
+    /// This is a const alternative to `TryFrom`:
+    ///
+    /// ```
+    /// use strict::NonEmptySlice;
+    /// const FALLBACKS: NonEmptySlice<'static, &str> = match NonEmptySlice::try_new(&["a", "b"]) {
+    ///     Some(s) => s,
+    ///     None => panic!(),
+    /// };
+    /// assert_eq!(FALLBACKS.last(), &"b");
+    /// ```
+    #[inline]
+    pub const fn try_new(slice: &'a [T]) -> Option<Self> {
+        if slice.is_empty() {
+            None
+        } else {
+            Some(Self { slice })
+        }
+    }
+
+    /// Build a slice of one element
+    #[inline]
+    pub const fn from_ref(value: &'a T) -> Self {
+        Self {
+            slice: slice::from_ref(value),
+        }
+    }
+
+    /// Wrap a slice without checking it's not empty
+    ///
+    /// With the `no_unsafe` feature, the accessors check the bounds, so
+    /// an empty slice makes them panic instead.
+    ///
+    /// # Safety
+    ///
+    /// The slice must not be empty: accessors like `len` skip the checks,
+    /// so calling them on an empty slice is undefined behavior.
+    #[allow(unsafe_code)]
+    #[inline]
+    pub const unsafe fn new_unchecked(slice: &'a [T]) -> Self {
+        debug_assert!(!slice.is_empty(), "empty slice");
+        Self { slice }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> NonZeroUsize {
+        unchecked::non_zero(self.slice.len())
+    }
+
+    #[inline]
+    pub fn has_len(&self, len: usize) -> bool {
+        self.slice.len() == len
+    }
+
+    #[inline]
+    pub const fn first(&self) -> &'a T {
+        match self.slice {
+            [first, ..] => first,
+            [] => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub const fn last(&self) -> &'a T {
+        match self.slice {
+            [.., last] => last,
+            [] => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub const fn as_slice(&self) -> &'a [T] {
+        self.slice
+    }
+
+    /// Return the element at `idx`, if any
+    #[inline]
+    pub fn get_ne(&self, idx: usize) -> Option<&'a T> {
+        self.slice.get(idx)
+    }
+
+    /// Return the elements of the range, or `None` if it's out of
+    /// bounds or doesn't select any element
+    pub fn subslice<R>(&self, range: R) -> Option<NonEmptySlice<'a, T>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i.checked_add(1)?,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => self.slice.len(),
+        };
+        if start < end {
+            self.slice
+                .get(start..end)
+                .map(|slice| NonEmptySlice { slice })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn split_first(&self) -> (&'a T, &'a [T]) {
+        match self.slice.split_first() {
+            Some(split) => split,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub fn split_last(&self) -> (&'a [T], &'a T) {
+        match self.slice.split_last() {
+            Some((last, init)) => (init, last),
+            None => unreachable!(),
+        }
+    }
+
+    /// Return all elements but the first one
+    #[inline]
+    pub fn rest(&self) -> &'a [T] {
+        self.split_first().1
+    }
+
+    /// Return all elements but the last one
+    #[inline]
+    pub fn init(&self) -> &'a [T] {
+        self.split_last().0
+    }
+
+    /// Return the first element and the other ones, or `None` if there's
+    /// no other one
+    #[inline]
+    pub fn split_first_non_empty(&self) -> Option<(&'a T, NonEmptySlice<'a, T>)> {
+        let (first, rest) = self.split_first();
+        if rest.is_empty() {
+            None
+        } else {
+            Some((first, NonEmptySlice { slice: rest }))
+        }
+    }
+
+    /// Return the first element as an array of length 1
+    #[inline]
+    pub fn first_array1(&self) -> &'a [T; 1] {
+        array::from_ref(self.first())
+    }
+
+    /// Return the first `N` elements, or `None` if there are fewer
+    #[inline]
+    pub fn first_chunk<const N: usize>(&self) -> Option<&'a [T; N]> {
+        self.slice.get(..N).and_then(|chunk| chunk.try_into().ok())
+    }
+
+    /// Return the last `N` elements, or `None` if there are fewer
+    #[inline]
+    pub fn last_chunk<const N: usize>(&self) -> Option<&'a [T; N]> {
+        let start = self.slice.len().checked_sub(N)?;
+        self.slice[start..].try_into().ok()
+    }
+
+    /// Return an iterator over references to the elements, which keeps
+    /// the knowledge that there's at least one element
+    #[inline]
+    pub fn iter_ne(&self) -> NonEmptyIter<slice::Iter<'a, T>> {
+        NonEmptyIter::new(self.slice.iter())
+    }
+
+    /// Return the greatest element (the last one if several are
+    /// equally maximum)
+    #[inline]
+    pub fn max_elem(&self) -> &'a T
+    where
+        T: Ord,
+    {
+        self.iter_ne().max()
+    }
+
+    /// Return the smallest element (the first one if several are
+    /// equally minimum)
+    #[inline]
+    pub fn min_elem(&self) -> &'a T
+    where
+        T: Ord,
+    {
+        self.iter_ne().min()
+    }
+
+    /// Return the element with the greatest key (the last one if several
+    /// are equally maximum)
+    #[inline]
+    pub fn max_by_key<B: Ord, F: FnMut(&T) -> B>(&self, mut f: F) -> &'a T {
+        self.iter_ne().max_by_key(|v| f(v))
+    }
+
+    /// Return the element with the smallest key (the first one if several
+    /// are equally minimum)
+    #[inline]
+    pub fn min_by_key<B: Ord, F: FnMut(&T) -> B>(&self, mut f: F) -> &'a T {
+        self.iter_ne().min_by_key(|v| f(v))
+    }
+
+    /// Return the greatest element according to `compare` (the last one
+    /// if several are equally maximum)
+    #[inline]
+    pub fn max_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> &'a T {
+        self.iter_ne().max_by(|a, b| compare(a, b))
+    }
+
+    /// Return the smallest element according to `compare` (the first one
+    /// if several are equally minimum)
+    #[inline]
+    pub fn min_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> &'a T {
+        self.iter_ne().min_by(|a, b| compare(a, b))
+    }
+
+    /// Clone the elements into a [`NonEmptyVec`].
+    ///
+    /// This is the owned counterpart of the slice: `ToOwned` can't be
+    /// implemented as `NonEmptySlice` is `Clone`, but a `NonEmptyVec`
+    /// can be borrowed as `[T]`, so it fits in a `Cow<[T]>`.
+    #[inline]
+    pub fn to_non_empty_vec(&self) -> NonEmptyVec<T>
+    where
+        T: Clone,
+    {
+        NonEmptyVec {
+            vec: self.slice.to_vec(),
+        }
+    }
+
+    /// Return an iterator over chunks of `size` elements, the last
+    /// one being possibly shorter (there's at least one chunk)
+    #[inline]
+    pub fn chunks(&self, size: NonZeroUsize) -> NonEmptyIter<Chunks<'a, T>> {
+        NonEmptyIter::new(Chunks {
+            iter: self.slice.chunks(size.get()),
+        })
+    }
+
+    /// Return an iterator over chunks of `size` elements, starting from
+    /// the end, the last one being possibly shorter (there's at least
+    /// one chunk)
+    #[inline]
+    pub fn rchunks(&self, size: NonZeroUsize) -> NonEmptyIter<RChunks<'a, T>> {
+        NonEmptyIter::new(RChunks {
+            iter: self.slice.rchunks(size.get()),
+        })
+    }
+
+    /// Return an iterator over chunks of exactly `size` elements, which
+    /// is empty if `size` is greater than the length
+    #[inline]
+    pub fn chunks_exact(&self, size: NonZeroUsize) -> ChunksExact<'a, T> {
+        ChunksExact {
+            iter: self.slice.chunks_exact(size.get()),
+        }
+    }
+
+    /// Return an iterator over the runs of consecutive elements for which
+    /// `pred` returns true, like `slice::chunk_by` (there's at least one run)
+    #[inline]
+    pub fn chunk_by<F>(&self, pred: F) -> NonEmptyIter<ChunkBy<'a, T, F>>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        NonEmptyIter::new(ChunkBy {
+            slice: self.slice,
+            pred,
+        })
+    }
+
+    /// Return an iterator over the subslices separated by the elements
+    /// matching `pred`, like `slice::split`.
+    ///
+    /// There's always at least one subslice, but subslices may be empty.
+    #[inline]
+    pub fn split<F>(&self, pred: F) -> NonEmptyIter<slice::Split<'a, T, F>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        NonEmptyIter::new(self.slice.split(pred))
+    }
+
+    /// Return an iterator over the subslices separated by the elements
+    /// matching `pred`, starting from the end, like `slice::rsplit`.
+    ///
+    /// There's always at least one subslice, but subslices may be empty.
+    #[inline]
+    pub fn rsplit<F>(&self, pred: F) -> NonEmptyIter<slice::RSplit<'a, T, F>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        NonEmptyIter::new(self.slice.rsplit(pred))
+    }
+
+    /// Return an iterator over at most `n` subslices separated by the
+    /// elements matching `pred`, the last one containing the remaining
+    /// elements, like `slice::splitn`
+    #[inline]
+    pub fn splitn<F>(&self, n: NonZeroUsize, pred: F) -> NonEmptyIter<slice::SplitN<'a, T, F>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        NonEmptyIter::new(self.slice.splitn(n.get(), pred))
+    }
+
+    /// Return an iterator over the non empty subslices separated by the
+    /// elements matching `pred` (there may be none, if all elements match)
+    #[inline]
+    pub fn split_non_empty<F>(&self, pred: F) -> SplitNonEmpty<'a, T, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        SplitNonEmpty {
+            iter: self.slice.split(pred),
+        }
+    }
+
+    /// Split the slice in `..mid` and `mid..`, failing if one of the
+    /// parts would be empty, that is when `mid` is 0 or not less than
+    /// the length
+    #[inline]
+    pub fn split_at_non_empty(
+        &self,
+        mid: usize,
+    ) -> Result<(NonEmptySlice<'a, T>, NonEmptySlice<'a, T>), NotEnoughElementsError> {
+        if mid == 0 || mid >= self.slice.len() {
+            Err(NotEnoughElementsError)
+        } else {
+            let (left, right) = self.slice.split_at(mid);
+            Ok((
+                NonEmptySlice { slice: left },
+                NonEmptySlice { slice: right },
+            ))
+        }
+    }
+
+    /// Split the slice in a non empty head `..mid` and a possibly
+    /// empty rest `mid..`.
+    ///
+    /// Unlike `slice::split_at`, this panics if `mid` is 0 (or greater
+    /// than the length).
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> (NonEmptySlice<'a, T>, &'a [T]) {
+        assert!(mid > 0, "the head would be empty");
+        let (head, rest) = self.slice.split_at(mid);
+        (NonEmptySlice { slice: head }, rest)
+    }
+
+    /// Return a displayable value writing the elements separated
+    /// by `sep`, with no allocation
+    #[inline]
+    pub fn display_join<'s>(&self, sep: &'s str) -> DisplayJoin<'s, T>
+    where
+        'a: 's,
+        T: fmt::Display,
+    {
+        self.display_join_with_last(sep, sep)
+    }
+
+    /// Return a displayable value writing the elements separated by
+    /// `sep`, except the last two ones which are separated by `last_sep`,
+    /// as in "a, b and c"
+    #[inline]
+    pub fn display_join_with_last<'s>(&self, sep: &'s str, last_sep: &'s str) -> DisplayJoin<'s, T>
+    where
+        'a: 's,
+        T: fmt::Display,
+    {
+        DisplayJoin {
+            slice: self.slice,
+            sep,
+            last_sep,
+        }
+    }
+}
+
+/// The elements of a non empty slice, displayed with separators.
+///
+/// Formatting flags (width, precision, etc.) apply to every element.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayJoin<'s, T> {
+    slice: &'s [T],
+    sep: &'s str,
+    last_sep: &'s str,
+}
+
+impl<T: fmt::Display> fmt::Display for DisplayJoin<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let last_idx = self.slice.len() - 1;
+        for (idx, item) in self.slice.iter().enumerate() {
+            if idx == last_idx && idx > 0 {
+                f.write_str(self.last_sep)?;
+            } else if idx > 0 {
+                f.write_str(self.sep)?;
+            }
+            item.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! chunk_iterator {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone)]
+        pub struct $name<'a, T> {
+            iter: slice::$name<'a, T>,
+        }
+
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = NonEmptySlice<'a, T>;
+            #[inline]
+            fn next(&mut self) -> Option<NonEmptySlice<'a, T>> {
+                self.iter.next().map(|slice| NonEmptySlice { slice })
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $name<'a, T> {
+            #[inline]
+            fn next_back(&mut self) -> Option<NonEmptySlice<'a, T>> {
+                self.iter.next_back().map(|slice| NonEmptySlice { slice })
+            }
+        }
+
+        impl<'a, T> ExactSizeIterator for $name<'a, T> {}
+
+        impl<'a, T> FusedIterator for $name<'a, T> {}
+    };
+}
+
+chunk_iterator!(
+    /// An iterator over chunks of a slice, as non empty slices
+    Chunks
+);
+chunk_iterator!(
+    /// An iterator over chunks of a slice, starting from its end,
+    /// as non empty slices
+    RChunks
+);
+chunk_iterator!(
+    /// An iterator over chunks of exactly the same size, as non empty
+    /// slices, the remaining elements being given by `remainder`
+    ChunksExact
+);
+
+impl<'a, T> ChunksExact<'a, T> {
+    /// Return the elements which don't fit in a chunk (possibly none)
+    #[inline]
+    pub fn remainder(&self) -> &'a [T] {
+        self.iter.remainder()
+    }
+}
+
+/// An iterator over runs of consecutive elements, as non empty slices
+pub struct ChunkBy<'a, T, F> {
+    slice: &'a [T],
+    pred: F,
+}
+
+impl<'a, T, F> Iterator for ChunkBy<'a, T, F>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    type Item = NonEmptySlice<'a, T>;
+    fn next(&mut self) -> Option<NonEmptySlice<'a, T>> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let mut len = 1;
+        while len < self.slice.len() && (self.pred)(&self.slice[len - 1], &self.slice[len]) {
+            len += 1;
+        }
+        let (run, rest) = self.slice.split_at(len);
+        self.slice = rest;
+        Some(NonEmptySlice { slice: run })
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len();
+        ((len > 0) as usize, Some(len))
+    }
+}
+
+impl<'a, T, F> FusedIterator for ChunkBy<'a, T, F> where F: FnMut(&T, &T) -> bool {}
+
+/// Flattening a non empty slice of non empty sequences can't produce
+/// an empty vec.
+///
+/// For other element types, like `&[T]` or `String`, the methods of
+/// the standard slice are used.
+impl<'a, S: NonEmpty> NonEmptySlice<'a, S> {
+    /// Concatenate the sequences into a [`NonEmptyVec`]
+    pub fn concat(&self) -> NonEmptyVec<S::Item>
+    where
+        S::Item: Clone,
+    {
+        join_non_empty(self.slice, &[])
+    }
+
+    /// Concatenate the sequences into a [`NonEmptyVec`], with a clone
+    /// of `sep` between each of them
+    pub fn join(&self, sep: &S::Item) -> NonEmptyVec<S::Item>
+    where
+        S::Item: Clone,
+    {
+        join_non_empty(self.slice, slice::from_ref(sep))
+    }
+}
+
+/// An iterator over the non empty subslices separated by the elements
+/// matching a predicate
+pub struct SplitNonEmpty<'a, T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    iter: slice::Split<'a, T, F>,
+}
+
+impl<'a, T, F> Iterator for SplitNonEmpty<'a, T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    type Item = NonEmptySlice<'a, T>;
+    fn next(&mut self) -> Option<NonEmptySlice<'a, T>> {
+        self.iter.by_ref().find_map(NonEmptySlice::try_new)
+    }
+}
+
+impl<'a, T, F> DoubleEndedIterator for SplitNonEmpty<'a, T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<NonEmptySlice<'a, T>> {
+        self.iter.by_ref().rev().find_map(NonEmptySlice::try_new)
+    }
+}
+
+impl<'a, T, F> FusedIterator for SplitNonEmpty<'a, T, F> where F: FnMut(&T) -> bool {}
+
+impl<'a, T> Clone for NonEmptySlice<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for NonEmptySlice<'a, T> {}
+
+impl<'a, T> TryFrom<&'a [T]> for NonEmptySlice<'a, T> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        if slice.is_empty() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self { slice })
+        }
+    }
+}
+
+/// Checked at compile time: an empty array doesn't compile.
+///
+/// As `From<&T>` is also implemented, the element type must usually be
+/// given:
+///
+/// ```
+/// use strict::NonEmptySlice;
+/// let slice = NonEmptySlice::<u8>::from(&[1, 2, 3]);
+/// assert!(slice.has_len(3));
+/// ```
+///
+/// ```compile_fail
+/// use strict::NonEmptySlice;
+/// let slice = NonEmptySlice::<u8>::from(&[]);
+/// ```
+impl<'a, T, const N: usize> From<&'a [T; N]> for NonEmptySlice<'a, T> {
+    #[inline]
+    fn from(array: &'a [T; N]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+impl<'a, T> From<&'a T> for NonEmptySlice<'a, T> {
+    #[inline]
+    fn from(value: &'a T) -> Self {
+        Self::from_ref(value)
+    }
+}
+
+impl<'a, T> Deref for NonEmptySlice<'a, T> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.slice
+    }
+}
+
+impl<'a, T, I: slice::SliceIndex<[T]>> Index<I> for NonEmptySlice<'a, T> {
+    type Output = I::Output;
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(self.slice, index)
+    }
+}
+
+impl<'a, T> IntoIterator for NonEmptySlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.slice.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &NonEmptySlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.slice.iter()
+    }
+}
+
+impl<'s, T> crate::non_empty::Sealed for NonEmptySlice<'s, T> {}
+
+impl<'s, T> NonEmpty for NonEmptySlice<'s, T> {
+    type Item = T;
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        Self: 'a;
+    #[inline]
+    fn len(&self) -> NonZeroUsize {
+        self.len()
+    }
+    #[inline]
+    fn first(&self) -> &T {
+        self.first()
+    }
+    #[inline]
+    fn last(&self) -> &T {
+        self.last()
+    }
+    #[inline]
+    fn iter(&self) -> slice::Iter<'_, T> {
+        self.slice.iter()
+    }
+}
+
+#[cfg(test)]
+mod non_empty_slice_tests {
+
+    use {super::*, std::convert::TryInto};
+
+    #[test]
+    fn test_min_max() {
+        let slice = NonEmptySlice::from_array(&["b", "a", "c", "a", "c"]);
+        assert_eq!(*slice.max_elem(), "c");
+        assert!(std::ptr::eq(slice.max_elem(), &slice[4]));
+        assert!(std::ptr::eq(slice.min_elem(), &slice[1]));
+        assert!(std::ptr::eq(slice.min_by_key(|s| s.len()), &slice[0]));
+        assert!(std::ptr::eq(slice.max_by_key(|s| s.len()), &slice[4]));
+        assert!(std::ptr::eq(slice.max_by(|a, b| b.cmp(a)), &slice[3]));
+        assert!(std::ptr::eq(slice.min_by(|a, b| b.cmp(a)), &slice[2]));
+    }
+
+    #[test]
+    fn test_chunks() {
+        let slice = NonEmptySlice::from_array(&[1, 2, 3, 4, 5]);
+        let two = NonZeroUsize::new(2).unwrap();
+        let chunks: Vec<&[u8]> = slice
+            .chunks(two)
+            .into_iter()
+            .map(|c| c.as_slice())
+            .collect();
+        assert_eq!(chunks, vec![&[1, 2][..], &[3, 4], &[5]]);
+        let rchunks: Vec<&[u8]> = slice
+            .rchunks(two)
+            .into_iter()
+            .map(|c| c.as_slice())
+            .collect();
+        assert_eq!(rchunks, vec![&[4, 5][..], &[2, 3], &[1]]);
+        let exact = slice.chunks_exact(two);
+        assert_eq!(exact.len(), 2);
+        assert_eq!(exact.remainder(), &[5]);
+        let five = NonZeroUsize::new(5).unwrap();
+        assert_eq!(slice.chunks(five).first().as_slice(), slice.as_slice());
+        assert!(slice.chunks_exact(five).remainder().is_empty());
+        let big = NonZeroUsize::new(10).unwrap();
+        assert_eq!(slice.chunks(big).first().as_slice(), slice.as_slice());
+        assert_eq!(slice.rchunks(big).first().as_slice(), slice.as_slice());
+        assert_eq!(slice.chunks_exact(big).count(), 0);
+        assert_eq!(slice.chunks_exact(big).remainder(), slice.as_slice());
+    }
+
+    #[test]
+    fn test_chunk_by() {
+        let slice = NonEmptySlice::from_array(&[1, 1, 1]);
+        let runs: Vec<_> = slice.chunk_by(|a, b| a == b).into_iter().collect();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].as_slice(), &[1, 1, 1]);
+        let slice = NonEmptySlice::from_array(&[1, 2, 1, 2]);
+        assert_eq!(slice.chunk_by(|a, b| a == b).into_iter().count(), 4);
+        let slice = NonEmptySlice::from_array(&[1, 2, 3, 2, 3, 4, 0]);
+        let runs = slice.chunk_by(|a, b| a < b);
+        let (first, rest) = runs.next_first();
+        assert_eq!(first.as_slice(), &[1, 2, 3]);
+        let rest: Vec<&[u8]> = rest.map(|run| run.as_slice()).collect();
+        assert_eq!(rest, vec![&[2, 3, 4][..], &[0]]);
+    }
+
+    #[test]
+    fn test_display_join() {
+        let names = NonEmptySlice::from_array(&["Ann", "Bob", "Cid"]);
+        assert_eq!(names.display_join(", ").to_string(), "Ann, Bob, Cid");
+        assert_eq!(
+            names.display_join_with_last(", ", " and ").to_string(),
+            "Ann, Bob and Cid"
+        );
+        let one = NonEmptySlice::from(&"Ann");
+        assert_eq!(one.display_join_with_last(", ", " and ").to_string(), "Ann");
+        let numbers = NonEmptySlice::from_array(&[1.0, 2.25]);
+        assert_eq!(format!("{:5.1}", numbers.display_join("|")), "  1.0|  2.2");
+    }
+
+    #[test]
+    fn test_chunk_arrays() {
+        let slice = NonEmptySlice::from_array(&['a', 'b']);
+        assert_eq!(slice.first_array1(), &['a']);
+        assert_eq!(slice.first_chunk::<1>(), Some(&['a']));
+        assert_eq!(slice.first_chunk::<2>(), Some(&['a', 'b']));
+        assert_eq!(slice.first_chunk::<3>(), None);
+        assert_eq!(slice.last_chunk::<1>(), Some(&['b']));
+        assert_eq!(slice.last_chunk::<2>(), Some(&['a', 'b']));
+        assert_eq!(slice.last_chunk::<3>(), None);
+    }
+
+    #[test]
+    fn test_split_at() {
+        let slice = NonEmptySlice::from_array(&[1, 2, 3]);
+        assert!(slice.split_at_non_empty(0).is_err());
+        assert!(slice.split_at_non_empty(3).is_err());
+        let (left, right) = slice.split_at_non_empty(1).unwrap();
+        assert_eq!((left.as_slice(), right.as_slice()), (&[1][..], &[2, 3][..]));
+        let (left, right) = slice.split_at_non_empty(2).unwrap();
+        assert_eq!((left.as_slice(), right.as_slice()), (&[1, 2][..], &[3][..]));
+        let (head, rest) = slice.split_at(1);
+        assert_eq!((head.as_slice(), rest), (&[1][..], &[2, 3][..]));
+        let (head, rest) = slice.split_at(3);
+        assert_eq!((head.len().get(), rest), (3, &[][..]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_zero() {
+        let _ = NonEmptySlice::from(&1).split_at(0);
+    }
+
+    #[test]
+    fn test_split_first_last() {
+        let slice = NonEmptySlice::from_array(&[1, 2, 3]);
+        assert_eq!(slice.split_first(), (&1, &[2, 3][..]));
+        assert_eq!(slice.split_last(), (&[1, 2][..], &3));
+        assert_eq!(slice.rest(), &[2, 3]);
+        assert_eq!(slice.init(), &[1, 2]);
+        let one = NonEmptySlice::from(&1);
+        assert_eq!(one.split_first(), (&1, &[][..]));
+        assert_eq!(one.split_last(), (&[][..], &1));
+        assert!(one.rest().is_empty());
+        assert!(one.init().is_empty());
+        assert!(one.split_first_non_empty().is_none());
+        let mut sum = 0;
+        let mut cur = slice;
+        while let Some((first, rest)) = cur.split_first_non_empty() {
+            sum += first;
+            cur = rest;
+        }
+        assert_eq!(sum + cur.first(), 6);
+    }
+
+    static PRIMES: NonEmptySlice<'static, u32> = match NonEmptySlice::try_new(&[2, 3, 5]) {
+        Some(s) => s,
+        None => panic!(),
+    };
+    const ANSWER: NonEmptySlice<'static, u32> = NonEmptySlice::from_ref(&42);
+    const DIGITS: NonEmptySlice<'static, u32> = NonEmptySlice::from_array(&[1, 2, 3]);
+    const LAST_DIGIT: u32 = *DIGITS.last();
+
+    #[test]
+    fn test_const_constructors() {
+        assert_eq!(PRIMES.first(), &2);
+        assert_eq!(PRIMES.as_slice(), &[2, 3, 5]);
+        assert_eq!(ANSWER.first(), ANSWER.last());
+        assert_eq!(LAST_DIGIT, 3);
+        assert!(NonEmptySlice::<u8>::try_new(&[]).is_none());
+    }
+
+    #[test]
+    fn test_subslice() {
+        let vec = vec![0, 1, 2, 3];
+        let sub = {
+            let slice = NonEmptySlice::try_from(vec.as_slice()).unwrap();
+            assert_eq!(slice.get_ne(3), Some(&3));
+            assert_eq!(slice.get_ne(4), None);
+            assert_eq!(slice.subslice(..).unwrap().as_slice(), &[0, 1, 2, 3]);
+            #[allow(clippy::reversed_empty_ranges)]
+            let inverted = slice.subslice(3..1);
+            assert!(inverted.is_none());
+            assert!(slice.subslice(2..2).is_none());
+            assert!(slice.subslice(2..5).is_none());
+            assert!(slice.subslice(4..).is_none());
+            assert!(slice.subslice(..=usize::MAX).is_none());
+            slice.subslice(1..=2).unwrap()
+        };
+        assert_eq!(sub.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_split() {
+        fn pieces(slice: &[u8]) -> Vec<&[u8]> {
+            let slice = NonEmptySlice::try_new(slice).unwrap();
+            slice.split(|&b| b == b',').into_iter().collect()
+        }
+        fn non_empty_pieces(slice: &[u8]) -> Vec<&[u8]> {
+            let slice = NonEmptySlice::try_new(slice).unwrap();
+            slice
+                .split_non_empty(|&b| b == b',')
+                .map(|piece| piece.as_slice())
+                .collect()
+        }
+        assert_eq!(pieces(b",a"), [&b""[..], b"a"]);
+        assert_eq!(pieces(b"a,"), [&b"a"[..], b""]);
+        assert_eq!(pieces(b"a,,b"), [&b"a"[..], b"", b"b"]);
+        assert_eq!(pieces(b"ab"), [&b"ab"[..]]);
+        assert_eq!(pieces(b","), [&b""[..], b""]);
+        assert_eq!(non_empty_pieces(b",a"), [&b"a"[..]]);
+        assert_eq!(non_empty_pieces(b"a,,b,"), [&b"a"[..], b"b"]);
+        assert_eq!(non_empty_pieces(b"ab"), [&b"ab"[..]]);
+        assert!(non_empty_pieces(b",,").is_empty());
+        let slice = NonEmptySlice::from_array(b"a,b,c");
+        assert_eq!(slice.split(|&b| b == b',').first(), b"a");
+        assert_eq!(slice.rsplit(|&b| b == b',').first(), b"c");
+        let two = NonZeroUsize::new(2).unwrap();
+        assert_eq!(slice.splitn(two, |&b| b == b',').last(), b"b,c");
+        let mut iter = slice.split_non_empty(|&b| b == b',');
+        assert_eq!(iter.next_back().unwrap().as_slice(), b"c");
+        assert_eq!(iter.next().unwrap().as_slice(), b"a");
+    }
+
+    #[test]
+    fn test_nes() {
+        const WEEKEND: NonEmptySlice<'static, &str> = nes!["Saturday", "Sunday",];
+        static ONE: NonEmptySlice<u64> = nes![1];
+        assert_eq!(WEEKEND.last(), &"Sunday");
+        assert!(ONE.has_len(1));
+        let letters = nes!['a', 'b'];
+        assert_eq!(letters.as_slice(), &['a', 'b']);
+        let array = [4, 5];
+        assert_eq!(NonEmptySlice::<i32>::from(&array).last(), &5);
+    }
+
+    #[test]
+    fn test_concat_join() {
+        let ab = NonEmptyVec::<String>::from_slice(&["a".into(), "b".into()]).unwrap();
+        let c = NonEmptyVec::new("c".to_string());
+        let seqs = [ab, c];
+        let seqs = NonEmptySlice::from_array(&seqs);
+        assert_eq!(seqs.concat(), ["a", "b", "c"]);
+        assert_eq!(seqs.join(&"-".to_string()), ["a", "b", "-", "c"]);
+        let single = NonEmptySlice::from_ref(&seqs[1]);
+        assert_eq!(single.concat(), ["c"]);
+        assert_eq!(single.join(&"-".to_string()), ["c"]);
+        let slices = [nes![1, 2], nes![3]];
+        let slices = NonEmptySlice::from_array(&slices);
+        assert_eq!(slices.join(&0).as_slice(), &[1, 2, 0, 3]);
+        // not a sequence of non empty sequences: the slice methods are used
+        let strings = ["ab".to_string(), String::new()];
+        let strings = NonEmptySlice::from_array(&strings);
+        assert_eq!(strings.concat(), "ab");
+        assert_eq!(strings.join("/"), "ab/");
+        let vecs = [vec![1], vec![]];
+        assert_eq!(NonEmptySlice::from_array(&vecs).concat(), vec![1]);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let empty: &[u8] = &[];
+        assert!(NonEmptySlice::try_from(empty).is_err());
+        let slice: NonEmptySlice<u8> = [3, 4][..].try_into().unwrap();
+        assert_eq!(slice.len().get(), 2);
+        assert_eq!(*slice.first(), 3);
+        assert_eq!(*slice.last(), 4);
+        let one = NonEmptySlice::from(&5);
+        assert!(one.has_len(1));
+        assert_eq!(one.first(), one.last());
+    }
+
+    #[test]
+    fn test_from_array() {
+        static PRIMES: NonEmptySlice<u32> = NonEmptySlice::from_array(&[2, 3, 5, 7]);
+        const SMALL_PRIMES: NonEmptySlice<u32> = NonEmptySlice::from_array(&[2, 3, 5]);
+        const LEN: NonZeroUsize = SMALL_PRIMES.len();
+        assert_eq!(PRIMES.len().get(), 4);
+        assert_eq!(*PRIMES.last(), 7);
+        assert_eq!(LEN.get(), 3);
+    }
+}
@@ -0,0 +1,273 @@
+use {
+    crate::{
+        unchecked, NonEmpty, NonEmptyIter, NonEmptySlice, NonEmptyVec, NotEnoughElementsError,
+    },
+    std::{
+        convert::TryFrom,
+        num::NonZeroUsize,
+        ops::{Deref, DerefMut, Index, IndexMut},
+        slice,
+    },
+};
+
+/// a mutable slice with at least one element.
+///
+/// The elements can be modified, but not removed, so it can't become
+/// empty.
+#[derive(Debug)]
+pub struct NonEmptySliceMut<'a, T> {
+    pub(crate) slice: &'a mut [T],
+}
+
+impl<'a, T> NonEmptySliceMut<'a, T> {
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unchecked::non_zero(self.slice.len())
+    }
+
+    #[inline]
+    pub fn has_len(&self, len: usize) -> bool {
+        self.slice.len() == len
+    }
+
+    #[inline]
+    pub fn first(&self) -> &T {
+        unchecked::first(self.slice)
+    }
+
+    #[inline]
+    pub fn first_mut(&mut self) -> &mut T {
+        unchecked::first_mut(self.slice)
+    }
+
+    #[inline]
+    pub fn last(&self) -> &T {
+        unchecked::last(self.slice)
+    }
+
+    #[inline]
+    pub fn last_mut(&mut self) -> &mut T {
+        unchecked::last_mut(self.slice)
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.slice
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.slice
+    }
+
+    /// Return the underlying slice, with the original lifetime
+    #[inline]
+    pub fn into_mut_slice(self) -> &'a mut [T] {
+        self.slice
+    }
+
+    #[inline]
+    pub fn as_non_empty_slice(&self) -> NonEmptySlice<'_, T> {
+        NonEmptySlice { slice: self.slice }
+    }
+
+    /// Return a shorter lived mutable view of the same elements
+    #[inline]
+    pub fn reborrow(&mut self) -> NonEmptySliceMut<'_, T> {
+        NonEmptySliceMut { slice: self.slice }
+    }
+
+    #[inline]
+    pub fn split_first_mut(&mut self) -> (&mut T, &mut [T]) {
+        match self.slice.split_first_mut() {
+            Some(split) => split,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub fn split_last_mut(&mut self) -> (&mut T, &mut [T]) {
+        match self.slice.split_last_mut() {
+            Some(split) => split,
+            None => unreachable!(),
+        }
+    }
+
+    /// Return an iterator over mutable references to the elements, which
+    /// keeps the knowledge that there's at least one element
+    #[inline]
+    pub fn iter_mut_ne(&mut self) -> NonEmptyIter<slice::IterMut<'_, T>> {
+        NonEmptyIter::new(self.slice.iter_mut())
+    }
+
+    #[inline]
+    pub fn to_non_empty_vec(&self) -> NonEmptyVec<T>
+    where
+        T: Clone,
+    {
+        NonEmptyVec {
+            vec: self.slice.to_vec(),
+        }
+    }
+}
+
+impl<'a, T> TryFrom<&'a mut [T]> for NonEmptySliceMut<'a, T> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(slice: &'a mut [T]) -> Result<Self, Self::Error> {
+        if slice.is_empty() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self { slice })
+        }
+    }
+}
+
+impl<'a, T> From<&'a mut T> for NonEmptySliceMut<'a, T> {
+    #[inline]
+    fn from(value: &'a mut T) -> Self {
+        Self {
+            slice: slice::from_mut(value),
+        }
+    }
+}
+
+impl<'a, T> From<&'a mut NonEmptyVec<T>> for NonEmptySliceMut<'a, T> {
+    #[inline]
+    fn from(vec: &'a mut NonEmptyVec<T>) -> Self {
+        vec.as_non_empty_slice_mut()
+    }
+}
+
+impl<'a, T> From<NonEmptySliceMut<'a, T>> for NonEmptySlice<'a, T> {
+    #[inline]
+    fn from(slice: NonEmptySliceMut<'a, T>) -> Self {
+        NonEmptySlice { slice: slice.slice }
+    }
+}
+
+impl<T> Deref for NonEmptySliceMut<'_, T> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.slice
+    }
+}
+
+impl<T> DerefMut for NonEmptySliceMut<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.slice
+    }
+}
+
+impl<T, I: slice::SliceIndex<[T]>> Index<I> for NonEmptySliceMut<'_, T> {
+    type Output = I::Output;
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(self.as_slice(), index)
+    }
+}
+
+impl<T, I: slice::SliceIndex<[T]>> IndexMut<I> for NonEmptySliceMut<'_, T> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(self.as_mut_slice(), index)
+    }
+}
+
+impl<'a, T> IntoIterator for NonEmptySliceMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+    #[inline]
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.slice.iter_mut()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut NonEmptySliceMut<'_, T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+    #[inline]
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.slice.iter_mut()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptySliceMut<'_, T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.slice.iter()
+    }
+}
+
+impl<T> crate::non_empty::Sealed for NonEmptySliceMut<'_, T> {}
+
+impl<T> NonEmpty for NonEmptySliceMut<'_, T> {
+    type Item = T;
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        Self: 'a;
+    #[inline]
+    fn len(&self) -> NonZeroUsize {
+        self.len()
+    }
+    #[inline]
+    fn first(&self) -> &T {
+        self.first()
+    }
+    #[inline]
+    fn last(&self) -> &T {
+        self.last()
+    }
+    #[inline]
+    fn iter(&self) -> slice::Iter<'_, T> {
+        self.slice.iter()
+    }
+}
+
+#[cfg(test)]
+mod non_empty_slice_mut_tests {
+
+    use super::*;
+
+    fn bump_first(mut slice: NonEmptySliceMut<'_, u32>) {
+        *slice.first_mut() += 100;
+    }
+
+    #[test]
+    fn test_reborrow() {
+        let mut vec = NonEmptyVec::from_slice(&[3, 1, 2]).unwrap();
+        let mut slice = vec.as_non_empty_slice_mut();
+        bump_first(slice.reborrow());
+        bump_first(slice.reborrow());
+        slice.sort_unstable();
+        *slice.last_mut() += 1;
+        slice[0] = 0;
+        assert_eq!(slice.as_slice(), &[0, 2, 204]);
+        for v in &mut slice {
+            *v *= 2;
+        }
+        assert_eq!(vec, [0, 4, 408]);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let mut empty: [u8; 0] = [];
+        assert!(NonEmptySliceMut::try_from(&mut empty[..]).is_err());
+        let mut array = [5, 6, 7];
+        let mut slice = NonEmptySliceMut::try_from(&mut array[1..]).unwrap();
+        let (first, rest) = slice.split_first_mut();
+        *first = 0;
+        assert_eq!(rest, &[7]);
+        assert_eq!(NonEmptySlice::from(slice).as_slice(), &[0, 7]);
+        let mut value = 'a';
+        let mut slice = NonEmptySliceMut::from(&mut value);
+        assert!(slice.has_len(1));
+        *slice.last_mut() = 'b';
+        assert_eq!(value, 'b');
+    }
+}
@@ -0,0 +1,399 @@
+use {
+    crate::{
+        unchecked, NonEmpty, NonEmptyIter, NonEmptySlice, NonEmptyVec, NotEnoughElementsError,
+        OneToThree,
+    },
+    smallvec::{smallvec, Array, SmallVec},
+    std::{
+        convert::TryFrom,
+        fmt,
+        num::NonZeroUsize,
+        ops::{Deref, Index, IndexMut},
+        slice,
+    },
+};
+
+/// a wrapping of a [`SmallVec`], ensuring there's always at least one element.
+///
+/// Follow the semantics of [`NonEmptyVec`], while keeping the elements
+/// inline as long as they fit in `A`.
+pub struct NonEmptySmallVec<A: Array> {
+    pub(crate) vec: SmallVec<A>,
+}
+
+impl<A: Array> NonEmptySmallVec<A> {
+    /// Create a vec with one element
+    ///
+    /// (there's no `From<A::Item>` impl as it would conflict with
+    /// `From<NonEmptyVec<A::Item>>`)
+    #[inline]
+    pub fn new(value: A::Item) -> Self {
+        Self {
+            vec: smallvec![value],
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unchecked::non_zero(self.vec.len())
+    }
+
+    #[inline]
+    pub fn has_len(&self, len: usize) -> bool {
+        self.vec.len() == len
+    }
+
+    /// Tell whether the elements were moved to the heap
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        self.vec.spilled()
+    }
+
+    #[inline]
+    pub fn first(&self) -> &A::Item {
+        unchecked::first(&self.vec)
+    }
+
+    #[inline]
+    pub fn first_mut(&mut self) -> &mut A::Item {
+        unchecked::first_mut(&mut self.vec)
+    }
+
+    #[inline]
+    pub fn last(&self) -> &A::Item {
+        unchecked::last(&self.vec)
+    }
+
+    #[inline]
+    pub fn last_mut(&mut self) -> &mut A::Item {
+        unchecked::last_mut(&mut self.vec)
+    }
+
+    /// Shrink the capacity, moving the elements back inline if they fit
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit();
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: A::Item) {
+        self.vec.push(value);
+    }
+
+    #[inline]
+    pub fn insert(&mut self, insertion_idx: usize, value: A::Item) {
+        self.vec.insert(insertion_idx, value);
+    }
+
+    /// Removes the last element and returns it, or [`None`] if it
+    /// contains only one element
+    #[inline]
+    pub fn pop(&mut self) -> Option<A::Item> {
+        if self.vec.len() == 1 {
+            None
+        } else {
+            self.vec.pop()
+        }
+    }
+
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> Result<A::Item, NotEnoughElementsError> {
+        if self.vec.len() == 1 {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(self.vec.remove(idx))
+        }
+    }
+
+    #[inline]
+    pub fn swap_remove(&mut self, idx: usize) -> Result<A::Item, NotEnoughElementsError> {
+        if self.vec.len() == 1 {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(self.vec.swap_remove(idx))
+        }
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[A::Item] {
+        &self.vec
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [A::Item] {
+        &mut self.vec
+    }
+
+    #[inline]
+    pub fn as_non_empty_slice(&self) -> NonEmptySlice<'_, A::Item> {
+        NonEmptySlice { slice: &self.vec }
+    }
+
+    /// Return an iterator over references to the elements, which keeps
+    /// the knowledge that there's at least one element
+    #[inline]
+    pub fn iter_ne(&self) -> NonEmptyIter<slice::Iter<'_, A::Item>> {
+        NonEmptyIter::new(self.vec.iter())
+    }
+
+    #[inline]
+    pub fn into_small_vec(self) -> SmallVec<A> {
+        self.vec
+    }
+
+    /// Convert into a [`NonEmptyVec`], without reallocating if the
+    /// elements are already on the heap.
+    ///
+    /// (there's no `From` impl as it would conflict with `From<T>`)
+    #[inline]
+    pub fn into_non_empty_vec(self) -> NonEmptyVec<A::Item> {
+        NonEmptyVec {
+            vec: self.vec.into_vec(),
+        }
+    }
+}
+
+impl<A: Array> TryFrom<SmallVec<A>> for NonEmptySmallVec<A> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(vec: SmallVec<A>) -> Result<Self, Self::Error> {
+        if vec.is_empty() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self { vec })
+        }
+    }
+}
+
+impl<A: Array> From<NonEmptyVec<A::Item>> for NonEmptySmallVec<A> {
+    #[inline]
+    fn from(vec: NonEmptyVec<A::Item>) -> Self {
+        Self {
+            vec: SmallVec::from_vec(vec.vec),
+        }
+    }
+}
+
+/// A [`OneToThree`] always fits inline in a `NonEmptySmallVec<[T; 3]>`
+impl<T> From<OneToThree<T>> for NonEmptySmallVec<[T; 3]> {
+    #[inline]
+    fn from(few: OneToThree<T>) -> Self {
+        let vec = match few {
+            OneToThree::One(a) => smallvec![a],
+            OneToThree::Two(a, b) => smallvec![a, b],
+            OneToThree::Three(a, b, c) => smallvec![a, b, c],
+        };
+        Self { vec }
+    }
+}
+
+/// (implemented for arrays as a generic `A` would conflict with `From<T>`)
+impl<T, const N: usize> TryFrom<SmallVec<[T; N]>> for NonEmptyVec<T>
+where
+    [T; N]: Array<Item = T>,
+{
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(vec: SmallVec<[T; N]>) -> Result<Self, Self::Error> {
+        NonEmptySmallVec::try_from(vec).map(NonEmptySmallVec::into_non_empty_vec)
+    }
+}
+
+impl<A: Array> From<NonEmptyVec<A::Item>> for SmallVec<A> {
+    #[inline]
+    fn from(vec: NonEmptyVec<A::Item>) -> Self {
+        SmallVec::from_vec(vec.vec)
+    }
+}
+
+/// Doesn't allocate
+impl<T> From<OneToThree<T>> for SmallVec<[T; 3]> {
+    #[inline]
+    fn from(few: OneToThree<T>) -> Self {
+        NonEmptySmallVec::from(few).vec
+    }
+}
+
+/// Fails only when the small vec is empty
+impl<T> TryFrom<SmallVec<[T; 3]>> for OneToThree<T> {
+    type Error = NotEnoughElementsError;
+    fn try_from(vec: SmallVec<[T; 3]>) -> Result<Self, Self::Error> {
+        let mut iter = vec.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(a), None, _) => Ok(Self::One(a)),
+            (Some(a), Some(b), None) => Ok(Self::Two(a, b)),
+            (Some(a), Some(b), Some(c)) => Ok(Self::Three(a, b, c)),
+            _ => Err(NotEnoughElementsError),
+        }
+    }
+}
+
+impl<A: Array> Clone for NonEmptySmallVec<A>
+where
+    A::Item: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec.clone(),
+        }
+    }
+}
+
+impl<A: Array> fmt::Debug for NonEmptySmallVec<A>
+where
+    A::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.vec.iter()).finish()
+    }
+}
+
+impl<A: Array> Deref for NonEmptySmallVec<A> {
+    type Target = [A::Item];
+    #[inline]
+    fn deref(&self) -> &[A::Item] {
+        &self.vec
+    }
+}
+
+impl<A: Array, I: slice::SliceIndex<[A::Item]>> Index<I> for NonEmptySmallVec<A> {
+    type Output = I::Output;
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(self.as_slice(), index)
+    }
+}
+
+impl<A: Array, I: slice::SliceIndex<[A::Item]>> IndexMut<I> for NonEmptySmallVec<A> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(self.as_mut_slice(), index)
+    }
+}
+
+impl<A: Array> IntoIterator for NonEmptySmallVec<A> {
+    type Item = A::Item;
+    type IntoIter = smallvec::IntoIter<A>;
+    #[inline]
+    fn into_iter(self) -> smallvec::IntoIter<A> {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, A: Array> IntoIterator for &'a NonEmptySmallVec<A> {
+    type Item = &'a A::Item;
+    type IntoIter = slice::Iter<'a, A::Item>;
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, A::Item> {
+        self.vec.iter()
+    }
+}
+
+impl<'a, A: Array> IntoIterator for &'a mut NonEmptySmallVec<A> {
+    type Item = &'a mut A::Item;
+    type IntoIter = slice::IterMut<'a, A::Item>;
+    #[inline]
+    fn into_iter(self) -> slice::IterMut<'a, A::Item> {
+        self.vec.iter_mut()
+    }
+}
+
+impl<A: Array> crate::non_empty::Sealed for NonEmptySmallVec<A> {}
+
+impl<A: Array> NonEmpty for NonEmptySmallVec<A> {
+    type Item = A::Item;
+    type Iter<'a>
+        = slice::Iter<'a, A::Item>
+    where
+        A: 'a;
+    #[inline]
+    fn len(&self) -> NonZeroUsize {
+        self.len()
+    }
+    #[inline]
+    fn first(&self) -> &A::Item {
+        self.first()
+    }
+    #[inline]
+    fn last(&self) -> &A::Item {
+        self.last()
+    }
+    #[inline]
+    fn iter(&self) -> slice::Iter<'_, A::Item> {
+        self.vec.iter()
+    }
+}
+
+#[cfg(test)]
+mod non_empty_small_vec_tests {
+
+    use {super::*, crate::NonEmptyIterator};
+
+    #[test]
+    fn test_pop_push() {
+        let mut vec = NonEmptySmallVec::<[u8; 2]>::new(1);
+        vec.push(2);
+        assert!(!vec.spilled());
+        vec.push(3);
+        assert!(vec.spilled());
+        assert_eq!(vec.pop(), Some(3));
+        assert_eq!(vec.remove(0).ok(), Some(1));
+        assert_eq!(vec.pop(), None);
+        assert!(vec.remove(0).is_err());
+        assert_eq!(*vec.first(), 2);
+        assert_eq!(*vec.last(), 2);
+        assert!(NonEmptySmallVec::<[u8; 2]>::try_from(SmallVec::new()).is_err());
+    }
+
+    #[test]
+    fn test_spilling() {
+        let mut vec = NonEmptySmallVec::<[u16; 2]>::new(1);
+        for i in 2..=10 {
+            vec.push(i);
+            assert_eq!(vec.spilled(), i > 2);
+            assert!(vec.has_len(i as usize));
+            assert_eq!(*vec.last(), i);
+        }
+        while vec.pop().is_some() {}
+        assert!(vec.spilled());
+        vec.shrink_to_fit();
+        assert!(!vec.spilled());
+        assert_eq!(vec.len().get(), 1);
+        assert_eq!(*vec.first(), 1);
+        assert!(vec.swap_remove(0).is_err());
+        vec.push(2);
+        vec.push(3);
+        assert_eq!(vec.swap_remove(0).ok(), Some(1));
+        assert_eq!(vec.into_iter().collect::<Vec<_>>(), vec![3, 2]);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let few = OneToThree::three('a', 'b', 'c');
+        let vec = NonEmptySmallVec::from(few);
+        assert!(!vec.spilled());
+        assert_eq!(vec.as_non_empty_slice().len().get(), 3);
+        assert_eq!(vec.iter_ne().max(), &'c');
+        let vec: NonEmptyVec<char> = vec.into_non_empty_vec();
+        let vec: NonEmptySmallVec<[char; 1]> = vec.into();
+        assert_eq!(vec.as_slice(), &['a', 'b', 'c']);
+    }
+
+    #[test]
+    fn test_interop() {
+        let small: SmallVec<[u8; 3]> = OneToThree::two(1, 2).into();
+        assert!(!small.spilled());
+        assert_eq!(
+            OneToThree::try_from(small).ok(),
+            Some(OneToThree::Two(1, 2))
+        );
+        assert!(OneToThree::<u8>::try_from(SmallVec::<[u8; 3]>::new()).is_err());
+        let small: SmallVec<[u8; 4]> = smallvec![4, 5];
+        let vec = NonEmptyVec::<u8>::try_from(small).unwrap();
+        assert_eq!(vec.as_slice(), &[4, 5]);
+        let small: SmallVec<[u8; 4]> = vec.into();
+        assert_eq!(small.as_slice(), &[4, 5]);
+        assert!(NonEmptyVec::<u8>::try_from(SmallVec::<[u8; 4]>::new()).is_err());
+    }
+}
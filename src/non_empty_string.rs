@@ -0,0 +1,273 @@
+use {
+    crate::{unchecked, NotEnoughElementsError},
+    std::{convert::TryFrom, fmt, num::NonZeroUsize, ops::Deref},
+};
+
+/// a mostly costless wrapping of a String, ensuring it's never empty.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyString {
+    pub(crate) string: String,
+}
+
+impl NonEmptyString {
+    /// Return the length in bytes
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unchecked::non_zero(self.string.len())
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    #[inline]
+    pub fn first_char(&self) -> char {
+        match self.string.chars().next() {
+            Some(c) => c,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        self.string.push_str(s);
+    }
+
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        self.string.push(c);
+    }
+
+    #[inline]
+    pub fn as_non_empty_str(&self) -> NonEmptyStr<'_> {
+        NonEmptyStr { str: &self.string }
+    }
+
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.string
+    }
+}
+
+impl TryFrom<String> for NonEmptyString {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        if string.is_empty() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self { string })
+        }
+    }
+}
+
+impl TryFrom<&str> for NonEmptyString {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from(s.to_string())
+    }
+}
+
+impl From<char> for NonEmptyString {
+    #[inline]
+    fn from(c: char) -> Self {
+        Self {
+            string: c.to_string(),
+        }
+    }
+}
+
+impl From<NonEmptyString> for String {
+    #[inline]
+    fn from(s: NonEmptyString) -> Self {
+        s.string
+    }
+}
+
+impl Deref for NonEmptyString {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.string
+    }
+}
+
+impl fmt::Display for NonEmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.string.fmt(f)
+    }
+}
+
+impl PartialEq<str> for NonEmptyString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.string == other
+    }
+}
+
+impl PartialEq<&str> for NonEmptyString {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.string == *other
+    }
+}
+
+/// Build a `NonEmptyStr<'static>` from a string literal, checked at
+/// compile time:
+///
+/// ```
+/// use strict::{non_empty_str, NonEmptyStr};
+/// static NAME: NonEmptyStr<'static> = non_empty_str!("strict");
+/// assert_eq!(NAME.first_char(), 's');
+/// ```
+///
+/// An empty literal doesn't compile:
+///
+/// ```compile_fail
+/// let _ = strict::non_empty_str!("");
+/// ```
+#[macro_export]
+macro_rules! non_empty_str {
+    ($s:literal) => {{
+        const STR: $crate::NonEmptyStr<'static> = $crate::NonEmptyStr::__from_literal($s);
+        STR
+    }};
+}
+
+/// Build a `NonEmptyString` from a string literal, checked at compile time:
+///
+/// ```
+/// let name = strict::non_empty_string!("strict");
+/// assert_eq!(name.len().get(), 6);
+/// ```
+///
+/// ```compile_fail
+/// let _ = strict::non_empty_string!("");
+/// ```
+#[macro_export]
+macro_rules! non_empty_string {
+    ($s:literal) => {
+        $crate::non_empty_str!($s).to_non_empty_string()
+    };
+}
+
+/// A borrowed string which is never empty
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyStr<'a> {
+    pub(crate) str: &'a str,
+}
+
+impl NonEmptyStr<'static> {
+    /// Only meant to be used by the `non_empty_str!` macro, in a const
+    #[doc(hidden)]
+    pub const fn __from_literal(str: &'static str) -> Self {
+        assert!(!str.is_empty(), "the string literal is empty");
+        Self { str }
+    }
+}
+
+impl<'a> NonEmptyStr<'a> {
+    /// Return the length in bytes
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unchecked::non_zero(self.str.len())
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.str
+    }
+
+    #[inline]
+    pub fn first_char(&self) -> char {
+        match self.str.chars().next() {
+            Some(c) => c,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub fn to_non_empty_string(&self) -> NonEmptyString {
+        NonEmptyString {
+            string: self.str.to_string(),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for NonEmptyStr<'a> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(str: &'a str) -> Result<Self, Self::Error> {
+        if str.is_empty() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self { str })
+        }
+    }
+}
+
+impl Deref for NonEmptyStr<'_> {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &str {
+        self.str
+    }
+}
+
+impl fmt::Display for NonEmptyStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.str.fmt(f)
+    }
+}
+
+impl PartialEq<str> for NonEmptyStr<'_> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.str == other
+    }
+}
+
+impl PartialEq<&str> for NonEmptyStr<'_> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.str == *other
+    }
+}
+
+#[cfg(test)]
+mod non_empty_string_tests {
+
+    use super::*;
+
+    #[test]
+    fn test_non_empty_string() {
+        assert!(NonEmptyString::try_from("").is_err());
+        let mut s = NonEmptyString::try_from("ab").unwrap();
+        assert_eq!(s.len().get(), 2);
+        assert_eq!(s.first_char(), 'a');
+        s.push('c');
+        assert_eq!(s, "abc");
+        assert_eq!(NonEmptyString::from('é').len().get(), 2);
+    }
+
+    #[test]
+    fn test_non_empty_str() {
+        assert!(NonEmptyStr::try_from("").is_err());
+        let s = NonEmptyStr::try_from("éa").unwrap();
+        assert_eq!(s.len().get(), 3);
+        assert_eq!(s.first_char(), 'é');
+        assert_eq!(s.to_non_empty_string(), "éa");
+        assert_eq!(s.to_non_empty_string().as_non_empty_str(), s);
+    }
+
+    #[test]
+    fn test_literal_macros() {
+        static EMPTY_MARK: NonEmptyStr<'static> = non_empty_str!("∅");
+        assert_eq!(EMPTY_MARK.first_char(), '∅');
+        let s = non_empty_string!("abc");
+        assert_eq!(s, "abc");
+        assert_eq!(s.as_non_empty_str(), non_empty_str!("abc"));
+    }
+}
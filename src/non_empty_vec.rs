@@ -1,26 +1,161 @@
-use std::{
-    convert::TryFrom,
-    num::NonZeroUsize,
-    ops::{Deref, Index, IndexMut},
-    slice,
+use {
+    crate::{
+        non_empty_slice::AssertNonEmpty, unchecked, ChunkBy, Chunks, ChunksExact, DisplayJoin,
+        NonEmpty, NonEmptyBoxedSlice, NonEmptyIter, NonEmptyIterator, NonEmptySlice,
+        NonEmptySliceMut, RChunks,
+    },
+    std::{
+        array,
+        borrow::Borrow,
+        cmp::Ordering,
+        convert::TryFrom,
+        fmt,
+        hash::{Hash, Hasher},
+        iter::FusedIterator,
+        mem,
+        num::NonZeroUsize,
+        ops::{Bound, Deref, DerefMut, RangeBounds},
+        rc::Rc,
+        slice,
+        str::FromStr,
+        sync::Arc,
+        thread, vec,
+    },
 };
 
+#[cfg(not(feature = "allocator_api"))]
+use std::ops::{Index, IndexMut};
+
 #[derive(Debug, Clone)]
 pub struct NotEnoughElementsError;
 
+impl fmt::Display for NotEnoughElementsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "not enough elements")
+    }
+}
+
+impl std::error::Error for NotEnoughElementsError {}
+
+/// Error returned by the non panicking operations of [`NonEmptyVec`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrictVecError {
+    IndexOutOfBounds {
+        idx: usize,
+        len: usize,
+    },
+    /// The operation would remove the only element
+    WouldBecomeEmpty,
+}
+
+impl fmt::Display for StrictVecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::IndexOutOfBounds { idx, len } => {
+                write!(f, "index {} is out of bounds for length {}", idx, len)
+            }
+            Self::WouldBecomeEmpty => write!(f, "the vec would become empty"),
+        }
+    }
+}
+
+impl std::error::Error for StrictVecError {}
+
+/// Error returned when parsing a delimited list into a [`NonEmptyVec`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseNonEmptyVecError<E> {
+    /// There's no item, the input being empty or made only of separators
+    Empty,
+    /// The item at this index (counting the empty ones) couldn't be parsed
+    Item { index: usize, error: E },
+}
+
+impl<E: fmt::Display> fmt::Display for ParseNonEmptyVecError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no item"),
+            Self::Item { index, error } => write!(f, "invalid item at index {}: {}", index, error),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for ParseNonEmptyVecError<E> {}
+
 /// a mostly costless wrapping of a vec, ensuring there's always at least one element.
 ///
 /// Follow the semantics of Vec (differing methods have a different name).
 ///
+/// With the `allocator_api` feature (nightly only), it's generic over the
+/// allocator, like `Vec`.
 #[derive(Debug, Clone)]
-pub struct NonEmptyVec<T> {
-    vec: Vec<T>,
+pub struct NonEmptyVec<
+    T,
+    #[cfg(feature = "allocator_api")] A: std::alloc::Allocator = std::alloc::Global,
+> {
+    #[cfg(not(feature = "allocator_api"))]
+    pub(crate) vec: Vec<T>,
+    #[cfg(feature = "allocator_api")]
+    pub(crate) vec: Vec<T, A>,
 }
 
-impl<T> NonEmptyVec<T> {
+/// Build a [`NonEmptyVec`], with the syntax of `vec!`:
+///
+/// ```
+/// use strict::nonempty_vec;
+/// let vec = nonempty_vec![1, 2, 3];
+/// assert_eq!(vec.as_slice(), &[1, 2, 3]);
+/// let vec = nonempty_vec!["a"; 2];
+/// assert_eq!(vec.as_slice(), &["a", "a"]);
+/// ```
+///
+/// Giving no element doesn't compile:
+///
+/// ```compile_fail
+/// let vec: strict::NonEmptyVec<u8> = strict::nonempty_vec![];
+/// ```
+///
+/// ```compile_fail
+/// let vec = strict::nonempty_vec![1u8; 0];
+/// ```
+#[macro_export]
+macro_rules! nonempty_vec {
+    () => {
+        compile_error!("a NonEmptyVec needs at least one element")
+    };
+    ($elem:expr; $n:literal) => {{
+        const N: ::std::num::NonZeroUsize = match ::std::num::NonZeroUsize::new($n) {
+            Some(n) => n,
+            None => panic!("a NonEmptyVec needs at least one element"),
+        };
+        $crate::NonEmptyVec::from_elem($elem, N)
+    }};
+    ($first:expr $(, $rest:expr)* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut vec = $crate::NonEmptyVec::new($first);
+        $(vec.push($rest);)*
+        vec
+    }};
+}
+
+/// Implement the methods for any allocator when the `allocator_api`
+/// feature is enabled, and for the global one otherwise
+macro_rules! impl_for_any_allocator {
+    ($($body:tt)*) => {
+        #[cfg(not(feature = "allocator_api"))]
+        impl<T> NonEmptyVec<T> {
+            $($body)*
+        }
+        #[cfg(feature = "allocator_api")]
+        impl<T, A: std::alloc::Allocator> NonEmptyVec<T, A> {
+            $($body)*
+        }
+    };
+}
+
+impl_for_any_allocator! {
     #[inline]
     pub fn len(&self) -> NonZeroUsize {
-        unsafe { NonZeroUsize::new_unchecked(self.vec.len()) }
+        unchecked::non_zero(self.vec.len())
     }
 
     #[inline]
@@ -30,29 +165,57 @@ impl<T> NonEmptyVec<T> {
 
     #[inline]
     pub fn first(&self) -> &T {
-        unsafe { self.vec.get_unchecked(0) }
+        unchecked::first(&self.vec)
     }
 
     #[inline]
     pub fn first_mut(&mut self) -> &mut T {
-        unsafe { self.vec.get_unchecked_mut(0) }
+        unchecked::first_mut(&mut self.vec)
     }
 
     #[inline]
     pub fn last(&self) -> &T {
-        unsafe { self.vec.get_unchecked(self.vec.len() - 1) }
+        unchecked::last(&self.vec)
     }
 
     #[inline]
     pub fn last_mut(&mut self) -> &mut T {
-        let idx = self.vec.len() - 1;
-        unsafe { self.vec.get_unchecked_mut(idx) }
+        unchecked::last_mut(&mut self.vec)
     }
 
-    /// take the first item, discard the rest
+    /// Return the first element as an array of length 1
     #[inline]
-    pub fn take(mut self) -> T {
-        self.vec.drain(..).next().unwrap()
+    pub fn first_array1(&self) -> &[T; 1] {
+        array::from_ref(self.first())
+    }
+
+    #[inline]
+    pub fn first_array1_mut(&mut self) -> &mut [T; 1] {
+        array::from_mut(self.first_mut())
+    }
+
+    /// Return the first `N` elements, or `None` if there are fewer
+    #[inline]
+    pub fn first_chunk<const N: usize>(&self) -> Option<&[T; N]> {
+        self.vec.get(..N).and_then(|chunk| chunk.try_into().ok())
+    }
+
+    #[inline]
+    pub fn first_chunk_mut<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+        self.vec.get_mut(..N).and_then(|chunk| chunk.try_into().ok())
+    }
+
+    /// Return the last `N` elements, or `None` if there are fewer
+    #[inline]
+    pub fn last_chunk<const N: usize>(&self) -> Option<&[T; N]> {
+        let start = self.vec.len().checked_sub(N)?;
+        self.vec[start..].try_into().ok()
+    }
+
+    #[inline]
+    pub fn last_chunk_mut<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+        let start = self.vec.len().checked_sub(N)?;
+        (&mut self.vec[start..]).try_into().ok()
     }
 
     #[inline]
@@ -60,11 +223,45 @@ impl<T> NonEmptyVec<T> {
         self.vec.push(value);
     }
 
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.vec.extend_from_slice(other);
+    }
+
     #[inline]
     pub fn insert(&mut self, insertion_idx: usize, value: T) {
         self.vec.insert(insertion_idx, value);
     }
 
+    /// Insert the value at `idx`, or give it back with the error if `idx`
+    /// is greater than the length
+    #[inline]
+    pub fn try_insert(&mut self, idx: usize, value: T) -> Result<(), (T, StrictVecError)> {
+        let len = self.vec.len();
+        if idx > len {
+            return Err((value, StrictVecError::IndexOutOfBounds { idx, len }));
+        }
+        self.vec.insert(idx, value);
+        Ok(())
+    }
+
+    /// Remove and return the element at `idx`, unless it's out of bounds
+    /// or the only one
+    #[inline]
+    pub fn try_remove(&mut self, idx: usize) -> Result<T, StrictVecError> {
+        let len = self.vec.len();
+        if idx >= len {
+            Err(StrictVecError::IndexOutOfBounds { idx, len })
+        } else if len == 1 {
+            Err(StrictVecError::WouldBecomeEmpty)
+        } else {
+            Ok(self.vec.remove(idx))
+        }
+    }
+
     /// Removes the last element from a vector and returns it, or [`None`] if it
     /// contains only one element
     #[inline]
@@ -76,6 +273,132 @@ impl<T> NonEmptyVec<T> {
         }
     }
 
+    /// Shorten the vec, keeping the first `len` elements (do nothing if
+    /// there aren't more)
+    #[inline]
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        self.vec.truncate(len.get());
+    }
+
+    /// Resize the vec, either truncating it or filling it with clones
+    /// of `value`
+    #[inline]
+    pub fn resize(&mut self, new_len: NonZeroUsize, value: T)
+    where
+        T: Clone,
+    {
+        self.vec.resize(new_len.get(), value);
+    }
+
+    /// Remove all elements but the first one
+    #[inline]
+    pub fn clear_keep_first(&mut self) {
+        self.vec.truncate(1);
+    }
+
+    /// Return mutable references to two distinct elements, or [`None`]
+    /// if the indices are equal or out of bounds
+    pub fn get_two_mut(&mut self, a: usize, b: usize) -> Option<(&mut T, &mut T)> {
+        let len = self.vec.len();
+        if a == b || a >= len || b >= len {
+            return None;
+        }
+        let (low, high) = (a.min(b), a.max(b));
+        let (head, tail) = self.vec.split_at_mut(high);
+        let (low_ref, high_ref) = (&mut head[low], &mut tail[0]);
+        if a < b {
+            Some((low_ref, high_ref))
+        } else {
+            Some((high_ref, low_ref))
+        }
+    }
+
+    /// Return mutable references to the elements at the given indices,
+    /// or [`None`] if some indices are equal or out of bounds.
+    ///
+    /// This is O(N²) for the checks, and O(N × max index) for the lookup.
+    pub fn get_disjoint_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        let len = self.vec.len();
+        for (i, &idx) in indices.iter().enumerate() {
+            if idx >= len || indices[..i].contains(&idx) {
+                return None;
+            }
+        }
+        let max = indices.iter().copied().max().unwrap_or(0);
+        let mut refs: [Option<&mut T>; N] = [(); N].map(|_| None);
+        for (idx, value) in self.vec.iter_mut().enumerate().take(max + 1) {
+            if let Some(pos) = indices.iter().position(|&i| i == idx) {
+                refs[pos] = Some(value);
+            }
+        }
+        Some(refs.map(|r| match r {
+            Some(r) => r,
+            None => unreachable!(),
+        }))
+    }
+
+    /// Return the first element and the other ones
+    #[inline]
+    pub fn split_first(&self) -> (&T, &[T]) {
+        match self.vec.split_first() {
+            Some(split) => split,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub fn split_first_mut(&mut self) -> (&mut T, &mut [T]) {
+        match self.vec.split_first_mut() {
+            Some(split) => split,
+            None => unreachable!(),
+        }
+    }
+
+    /// Return the elements before the last one, and the last one
+    #[inline]
+    pub fn split_last(&self) -> (&[T], &T) {
+        match self.vec.split_last() {
+            Some((last, init)) => (init, last),
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub fn split_last_mut(&mut self) -> (&mut [T], &mut T) {
+        match self.vec.split_last_mut() {
+            Some((last, init)) => (init, last),
+            None => unreachable!(),
+        }
+    }
+
+    /// Remove consecutive repeated elements
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.vec.dedup();
+    }
+
+    /// Remove consecutive elements for which `same_bucket` returns true
+    #[inline]
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.vec.dedup_by(same_bucket);
+    }
+
+    /// Remove consecutive elements which resolve to the same key
+    #[inline]
+    pub fn dedup_by_key<K, F>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.vec.dedup_by_key(key);
+    }
+
     #[inline]
     pub fn as_slice(&self) -> &[T] {
         &self.vec
@@ -105,80 +428,2103 @@ impl<T> NonEmptyVec<T> {
     }
 }
 
-impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
-    type Error = NotEnoughElementsError;
+impl<T> NonEmptyVec<T> {
+    /// Create a vec with one element
     #[inline]
-    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
-        if vec.is_empty() {
+    pub fn new(first: T) -> Self {
+        Self { vec: vec![first] }
+    }
+
+    /// Create a vec with one element and room for at least `capacity`
+    /// elements
+    #[inline]
+    pub fn with_capacity(first: T, capacity: usize) -> Self {
+        let mut vec = Vec::with_capacity(capacity.max(1));
+        vec.push(first);
+        Self { vec }
+    }
+
+    /// Create a vec of `n` clones of `value`, like `vec![value; n]`
+    #[inline]
+    pub fn from_elem(value: T, n: NonZeroUsize) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            vec: vec![value; n.get()],
+        }
+    }
+
+    /// Create a vec from its first element and the other ones
+    pub fn from_first_and_rest<I>(first: T, rest: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let rest = rest.into_iter();
+        let mut vec = Vec::with_capacity(1 + rest.size_hint().0);
+        vec.push(first);
+        vec.extend(rest);
+        Self { vec }
+    }
+
+    /// Wrap a vec without checking it's not empty
+    ///
+    /// With the `no_unsafe` feature, the accessors check the bounds, so
+    /// an empty vec makes them panic instead.
+    ///
+    /// # Safety
+    ///
+    /// The vec must not be empty: accessors like `first` or `len` skip
+    /// the checks, so calling them on an empty vec is undefined behavior.
+    #[allow(unsafe_code)]
+    #[inline]
+    pub unsafe fn new_unchecked(vec: Vec<T>) -> Self {
+        debug_assert!(!vec.is_empty(), "empty vec");
+        Self { vec }
+    }
+
+    /// Create a vec by cloning the elements of a slice, failing if it's empty
+    #[inline]
+    pub fn from_slice(slice: &[T]) -> Result<Self, NotEnoughElementsError>
+    where
+        T: Clone,
+    {
+        if slice.is_empty() {
             Err(NotEnoughElementsError)
         } else {
-            Ok(Self { vec })
+            Ok(Self {
+                vec: slice.to_vec(),
+            })
         }
     }
-}
 
-impl<T> From<T> for NonEmptyVec<T> {
+    /// take the first item, discard the rest
     #[inline]
-    fn from(value: T) -> Self {
-        Self { vec: vec![value] }
+    pub fn take(mut self) -> T {
+        self.vec.swap_remove(0)
     }
-}
 
-impl<T> Deref for NonEmptyVec<T> {
-    type Target = [T];
-    fn deref(&self) -> &[T] {
-        self.vec.deref()
+    /// Return the greatest element (the last one if several are
+    /// equally maximum).
+    ///
+    /// It's not named `max`, which would collide with `Ord::max`, as a
+    /// `NonEmptyVec` of `Ord` elements is itself `Ord`.
+    ///
+    /// ```
+    /// let vec = strict::nonempty_vec![2, 5, 1];
+    /// assert_eq!(vec.max_elem(), &5);
+    /// ```
+    #[inline]
+    pub fn max_elem(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.iter_ne().max()
     }
-}
 
-impl<T, I: slice::SliceIndex<[T]>> Index<I> for NonEmptyVec<T> {
-    type Output = I::Output;
+    /// Return the smallest element (the first one if several are
+    /// equally minimum)
     #[inline]
-    fn index(&self, index: I) -> &Self::Output {
-        Index::index(self.as_slice(), index)
+    pub fn min_elem(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.iter_ne().min()
     }
-}
 
-impl<T, I: slice::SliceIndex<[T]>> IndexMut<I> for NonEmptyVec<T> {
+    /// Return the element with the greatest key (the last one if several
+    /// are equally maximum)
     #[inline]
-    fn index_mut(&mut self, index: I) -> &mut Self::Output {
-        IndexMut::index_mut(self.as_mut_slice(), index)
+    pub fn max_by_key<B: Ord, F: FnMut(&T) -> B>(&self, mut f: F) -> &T {
+        self.iter_ne().max_by_key(|v| f(v))
     }
-}
 
-impl<'a, T> IntoIterator for &'a mut NonEmptyVec<T> {
-    type Item = &'a mut T;
-    type IntoIter = slice::IterMut<'a, T>;
+    /// Return the element with the smallest key (the first one if several
+    /// are equally minimum)
     #[inline]
-    fn into_iter(self) -> slice::IterMut<'a, T> {
-        self.vec.iter_mut()
+    pub fn min_by_key<B: Ord, F: FnMut(&T) -> B>(&self, mut f: F) -> &T {
+        self.iter_ne().min_by_key(|v| f(v))
     }
-}
 
-impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
-    type Item = &'a T;
-    type IntoIter = slice::Iter<'a, T>;
+    /// Return the greatest element according to `compare` (the last one
+    /// if several are equally maximum)
     #[inline]
-    fn into_iter(self) -> slice::Iter<'a, T> {
-        self.vec.iter()
+    pub fn max_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> &T {
+        self.iter_ne().max_by(|a, b| compare(a, b))
     }
-}
 
-#[cfg(test)]
-mod non_empty_vec_tests {
+    /// Return the smallest element according to `compare` (the first one
+    /// if several are equally minimum)
+    #[inline]
+    pub fn min_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> &T {
+        self.iter_ne().min_by(|a, b| compare(a, b))
+    }
 
-    use {super::*, std::convert::TryInto};
+    /// Return a non empty slice of the whole vec
+    #[inline]
+    pub fn as_non_empty_slice(&self) -> NonEmptySlice<'_, T> {
+        NonEmptySlice { slice: &self.vec }
+    }
 
-    #[test]
-    fn test_pop_push() {
-        let mut vec: NonEmptyVec<usize> = vec![1, 2].try_into().unwrap();
-        assert_eq!(vec.pop(), Some(2));
-        assert_eq!(vec.pop(), None);
-        assert_eq!(vec[0], 1);
-        vec[0] = 0;
-        assert_eq!(*vec.first(), 0);
-        let first: &mut usize = vec.first_mut();
-        *first = 4;
-        assert_eq!(vec[0], 4);
+    /// Return a mutable non empty slice of the whole vec
+    #[inline]
+    pub fn as_non_empty_slice_mut(&mut self) -> NonEmptySliceMut<'_, T> {
+        NonEmptySliceMut {
+            slice: &mut self.vec,
+        }
+    }
+
+    /// Return an iterator over chunks of `size` elements, the last
+    /// one being possibly shorter (there's at least one chunk)
+    #[inline]
+    pub fn chunks(&self, size: NonZeroUsize) -> NonEmptyIter<Chunks<'_, T>> {
+        self.as_non_empty_slice().chunks(size)
+    }
+
+    /// Return an iterator over chunks of `size` elements, starting from
+    /// the end, the last one being possibly shorter (there's at least
+    /// one chunk)
+    #[inline]
+    pub fn rchunks(&self, size: NonZeroUsize) -> NonEmptyIter<RChunks<'_, T>> {
+        self.as_non_empty_slice().rchunks(size)
+    }
+
+    /// Return an iterator over chunks of exactly `size` elements, which
+    /// is empty if `size` is greater than the length
+    #[inline]
+    pub fn chunks_exact(&self, size: NonZeroUsize) -> ChunksExact<'_, T> {
+        self.as_non_empty_slice().chunks_exact(size)
+    }
+
+    /// Return an iterator over the runs of consecutive elements for which
+    /// `pred` returns true, like `slice::chunk_by` (there's at least one run)
+    #[inline]
+    pub fn chunk_by<F>(&self, pred: F) -> NonEmptyIter<ChunkBy<'_, T, F>>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.as_non_empty_slice().chunk_by(pred)
+    }
+
+    /// Split the vec in `..mid` and `mid..`, failing if one of the
+    /// parts would be empty, that is when `mid` is 0 or not less than
+    /// the length
+    #[inline]
+    pub fn split_at_non_empty(
+        &self,
+        mid: usize,
+    ) -> Result<(NonEmptySlice<'_, T>, NonEmptySlice<'_, T>), NotEnoughElementsError> {
+        self.as_non_empty_slice().split_at_non_empty(mid)
+    }
+
+    /// Split the vec in a non empty head `..mid` and a possibly
+    /// empty rest `mid..`.
+    ///
+    /// Unlike `slice::split_at`, this panics if `mid` is 0 (or greater
+    /// than the length).
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> (NonEmptySlice<'_, T>, &[T]) {
+        self.as_non_empty_slice().split_at(mid)
+    }
+
+    /// Return a displayable value writing the elements separated
+    /// by `sep`, with no allocation
+    #[inline]
+    pub fn display_join<'s>(&'s self, sep: &'s str) -> DisplayJoin<'s, T>
+    where
+        T: fmt::Display,
+    {
+        self.as_non_empty_slice().display_join(sep)
+    }
+
+    /// Return a displayable value writing the elements separated by
+    /// `sep`, except the last two ones which are separated by `last_sep`
+    #[inline]
+    pub fn display_join_with_last<'s>(
+        &'s self,
+        sep: &'s str,
+        last_sep: &'s str,
+    ) -> DisplayJoin<'s, T>
+    where
+        T: fmt::Display,
+    {
+        self.as_non_empty_slice()
+            .display_join_with_last(sep, last_sep)
+    }
+
+    /// Build a vec by concatenating `n` copies of this one
+    #[inline]
+    pub fn repeat(&self, n: NonZeroUsize) -> NonEmptyVec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.vec.len() * n.get());
+        for _ in 0..n.get() {
+            vec.extend_from_slice(&self.vec);
+        }
+        NonEmptyVec { vec }
+    }
+
+    /// Sort the vec (with a stable sort) and return it
+    #[inline]
+    pub fn into_sorted(mut self) -> Self
+    where
+        T: Ord,
+    {
+        self.vec.sort();
+        self
+    }
+
+    /// Sort the vec with a comparator function (with a stable sort)
+    /// and return it
+    #[inline]
+    pub fn into_sorted_by<F>(mut self, compare: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.vec.sort_by(compare);
+        self
+    }
+
+    /// Sort the vec with a key extraction function (with a stable sort)
+    /// and return it
+    #[inline]
+    pub fn into_sorted_by_key<K, F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.vec.sort_by_key(f);
+        self
+    }
+
+    /// Sort the vec (with an unstable sort, faster but not preserving the
+    /// order of equal elements) and return it
+    #[inline]
+    pub fn into_sorted_unstable(mut self) -> Self
+    where
+        T: Ord,
+    {
+        self.vec.sort_unstable();
+        self
+    }
+
+    #[inline]
+    pub fn into_sorted_unstable_by<F>(mut self, compare: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.vec.sort_unstable_by(compare);
+        self
+    }
+
+    #[inline]
+    pub fn into_sorted_unstable_by_key<K, F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.vec.sort_unstable_by_key(f);
+        self
+    }
+
+    /// Pair the elements of both vecs, stopping at the end of the
+    /// shorter one
+    #[inline]
+    pub fn zip<B>(self, other: NonEmptyVec<B>) -> NonEmptyVec<(T, B)> {
+        self.zip_with(other, |a, b| (a, b))
+    }
+
+    /// Combine the elements of both vecs with `f`, stopping at the end
+    /// of the shorter one
+    pub fn zip_with<B, C, F>(self, other: NonEmptyVec<B>, mut f: F) -> NonEmptyVec<C>
+    where
+        F: FnMut(T, B) -> C,
+    {
+        let mut vec = Vec::with_capacity(self.vec.len().min(other.vec.len()));
+        vec.extend(self.vec.into_iter().zip(other.vec).map(|(a, b)| f(a, b)));
+        NonEmptyVec { vec }
+    }
+
+    /// Reduce the elements to one by repeatedly applying `f`, from left
+    /// to right
+    #[inline]
+    pub fn reduce<F>(self, f: F) -> T
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.into_iter_ne().reduce(f)
+    }
+
+    /// Fold the elements from left to right, the accumulator being
+    /// initialized from the first element
+    #[inline]
+    pub fn fold_first<B, I, F>(&self, init: I, f: F) -> B
+    where
+        I: FnOnce(&T) -> B,
+        F: FnMut(B, &T) -> B,
+    {
+        let (first, rest) = self.split_first();
+        rest.iter().fold(init(first), f)
+    }
+
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.vec
+    }
+
+    /// Return the first element and the vec of the other ones
+    /// (this is O(n) as the other elements are shifted)
+    #[inline]
+    pub fn into_first_rest(mut self) -> (T, Vec<T>) {
+        let first = self.vec.remove(0);
+        (first, self.vec)
+    }
+
+    /// Return the first element and an iterator over the other ones
+    /// (this is O(1), nothing is shifted)
+    #[inline]
+    pub fn into_first_and_rest(self) -> (T, vec::IntoIter<T>) {
+        let mut iter = self.vec.into_iter();
+        match iter.next() {
+            Some(first) => (first, iter),
+            None => unreachable!(),
+        }
+    }
+
+    /// Return an iterator over references to the elements, which keeps
+    /// the knowledge that there's at least one element
+    #[inline]
+    pub fn iter_ne(&self) -> NonEmptyIter<slice::Iter<'_, T>> {
+        NonEmptyIter::new(self.vec.iter())
+    }
+
+    /// Convert into an iterator over the elements, which keeps
+    /// the knowledge that there's at least one element
+    #[inline]
+    pub fn into_iter_ne(self) -> NonEmptyIter<vec::IntoIter<T>> {
+        NonEmptyIter::new(self.vec.into_iter())
+    }
+
+    /// Parse a list of items separated by `sep`.
+    ///
+    /// Items are trimmed, and the empty ones are skipped, so that `"a,b,"`
+    /// gives `["a", "b"]`. An input without any item (e.g. `""` or `" , "`)
+    /// is an error.
+    pub fn parse_delimited(s: &str, sep: char) -> Result<Self, ParseNonEmptyVecError<T::Err>>
+    where
+        T: FromStr,
+    {
+        let mut vec = Vec::new();
+        for (index, item) in s.split(sep).enumerate() {
+            let item = item.trim();
+            if item.is_empty() {
+                continue;
+            }
+            let value = item
+                .parse()
+                .map_err(|error| ParseNonEmptyVecError::Item { index, error })?;
+            vec.push(value);
+        }
+        Self::try_from(vec).map_err(|_| ParseNonEmptyVecError::Empty)
+    }
+
+    /// Build a new non empty vec by applying `f` to all elements
+    #[inline]
+    pub fn map<B, F>(self, f: F) -> NonEmptyVec<B>
+    where
+        F: FnMut(T) -> B,
+    {
+        let mut vec = Vec::with_capacity(self.vec.len());
+        vec.extend(self.vec.into_iter().map(f));
+        NonEmptyVec { vec }
+    }
+
+    /// Build a new non empty vec by applying `f` to references to all elements
+    #[inline]
+    pub fn map_ref<B, F>(&self, f: F) -> NonEmptyVec<B>
+    where
+        F: FnMut(&T) -> B,
+    {
+        let mut vec = Vec::with_capacity(self.vec.len());
+        vec.extend(self.vec.iter().map(f));
+        NonEmptyVec { vec }
+    }
+
+    /// Build a new non empty vec by applying `f` to all elements, stopping
+    /// at the first error
+    pub fn try_map<B, E, F>(self, f: F) -> Result<NonEmptyVec<B>, E>
+    where
+        F: FnMut(T) -> Result<B, E>,
+    {
+        let mut vec = Vec::with_capacity(self.vec.len());
+        for value in self.vec.into_iter().map(f) {
+            vec.push(value?);
+        }
+        Ok(NonEmptyVec { vec })
+    }
+
+    /// Build a new non empty vec by applying `f` to references to all
+    /// elements, stopping at the first error
+    pub fn try_map_ref<B, E, F>(&self, f: F) -> Result<NonEmptyVec<B>, E>
+    where
+        F: FnMut(&T) -> Result<B, E>,
+    {
+        let mut vec = Vec::with_capacity(self.vec.len());
+        for value in self.vec.iter().map(f) {
+            vec.push(value?);
+        }
+        Ok(NonEmptyVec { vec })
+    }
+
+    /// Keep only the elements whose flag is true, or fail without
+    /// removing anything if there's none
+    fn retain_flagged(&mut self, flags: Vec<bool>) -> Result<(), NotEnoughElementsError> {
+        if !flags.contains(&true) {
+            return Err(NotEnoughElementsError);
+        }
+        let mut flags = flags.into_iter();
+        self.vec.retain(|_| flags.next() == Some(true));
+        Ok(())
+    }
+
+    /// Retain only the elements for which `pred` returns true, unless
+    /// this would remove them all, in which case the vec is left unchanged.
+    ///
+    /// The predicate is called exactly once per element, in order.
+    pub fn try_retain<F>(&mut self, mut pred: F) -> Result<(), NotEnoughElementsError>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let flags = self.vec.iter().map(&mut pred).collect();
+        self.retain_flagged(flags)
+    }
+
+    /// Retain only the elements for which `pred` returns true, unless
+    /// this would remove them all, in which case the vec is left with
+    /// the changes made by `pred` but with no element removed.
+    ///
+    /// The predicate is called exactly once per element, in order.
+    pub fn try_retain_mut<F>(&mut self, mut pred: F) -> Result<(), NotEnoughElementsError>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let flags = self.vec.iter_mut().map(&mut pred).collect();
+        self.retain_flagged(flags)
+    }
+
+    /// Retain only the elements for which `pred` returns true, or only
+    /// the first element if `pred` rejects them all.
+    ///
+    /// The predicate is called exactly once per element, in order.
+    pub fn retain_or_first<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let flags = self.vec.iter().map(&mut pred).collect();
+        if self.retain_flagged(flags).is_err() {
+            self.vec.truncate(1);
+        }
+    }
+
+    /// Remove the elements of the range and return them as an iterator,
+    /// like `Vec::drain`, unless the range covers the whole vec.
+    ///
+    /// As with `Vec::drain`, the elements are removed even if the iterator
+    /// isn't fully consumed, and leaking it (e.g. with `mem::forget`) may
+    /// leak elements after the range, but the vec is never left empty.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn try_drain<R>(&mut self, range: R) -> Result<vec::Drain<'_, T>, NotEnoughElementsError>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.vec.len();
+        let (start, end) = range_to_bounds(range, len);
+        if start > 0 {
+            // a leak would truncate the vec to start elements
+            Ok(self.vec.drain(start..end))
+        } else if end < len {
+            // move the first kept element in front, so that a leak
+            // can't empty the vec
+            self.vec[..=end].rotate_right(1);
+            Ok(self.vec.drain(1..=end))
+        } else {
+            Err(NotEnoughElementsError)
+        }
+    }
+
+    /// Replace the elements of the range with the ones of `replace_with`,
+    /// like `Vec::splice`, and return the removed elements, unless the
+    /// range covers the whole vec and `replace_with` is empty.
+    ///
+    /// When the range covers the whole vec, `replace_with` is collected
+    /// before the vec is touched. Otherwise it's consumed while splicing,
+    /// which can't empty the vec as the elements out of the range stay.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn try_splice<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<Vec<T>, NotEnoughElementsError>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let len = self.vec.len();
+        let (start, end) = range_to_bounds(range, len);
+        if start > 0 || end < len {
+            Ok(self.vec.splice(start..end, replace_with).collect())
+        } else {
+            let replacement: Vec<T> = replace_with.into_iter().collect();
+            if replacement.is_empty() {
+                Err(NotEnoughElementsError)
+            } else {
+                Ok(mem::replace(&mut self.vec, replacement))
+            }
+        }
+    }
+
+    /// Return an iterator removing and yielding the elements for which
+    /// `filter` returns true, in order, like the nightly `Vec::extract_if`,
+    /// except that the last remaining element is never extracted.
+    ///
+    /// So when all elements match, all but the last one are extracted.
+    /// Elements not reached by the iterator (because it's dropped before
+    /// its end) are kept.
+    ///
+    /// This is linear in the length of the vec, but the kept elements
+    /// are moved to a new buffer.
+    #[inline]
+    pub fn extract_if<F>(&mut self, filter: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        // The elements to check are moved out, and the kept ones pushed
+        // back. The last element stays in the vec until the end, so that
+        // it's never empty, even if the iterator is leaked.
+        let mut rest = mem::take(&mut self.vec);
+        let capacity = rest.len();
+        let last = match rest.pop() {
+            Some(last) => last,
+            None => unreachable!(),
+        };
+        self.vec = Vec::with_capacity(capacity);
+        self.vec.push(last);
+        ExtractIf {
+            vec: &mut self.vec,
+            rest: rest.into_iter(),
+            last_checked: false,
+            filter,
+        }
+    }
+
+    /// Remove up to `n` elements from the end, always keeping at least
+    /// one, and return them in their order in the vec (which is the
+    /// reverse of the pop order)
+    #[inline]
+    pub fn pop_many(&mut self, n: usize) -> Vec<T> {
+        let len = self.vec.len();
+        self.vec.split_off(len - n.min(len - 1))
+    }
+
+    /// Shorten the vec, keeping the first `len` elements, and return the
+    /// removed ones (none if there weren't more)
+    #[inline]
+    pub fn truncate_back_to(&mut self, len: NonZeroUsize) -> Vec<T> {
+        if len.get() < self.vec.len() {
+            self.vec.split_off(len.get())
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Split the elements in the ones for which `pred` returns true and
+    /// the other ones, keeping their relative order, and telling which
+    /// sides aren't empty
+    pub fn partition<F>(self, mut pred: F) -> Partitioned<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (matched, unmatched): (Vec<T>, Vec<T>) = self.vec.into_iter().partition(|t| pred(t));
+        if unmatched.is_empty() {
+            Partitioned::AllMatched(NonEmptyVec { vec: matched })
+        } else if matched.is_empty() {
+            Partitioned::NoneMatched(NonEmptyVec { vec: unmatched })
+        } else {
+            Partitioned::Mixed {
+                matched: NonEmptyVec { vec: matched },
+                unmatched: NonEmptyVec { vec: unmatched },
+            }
+        }
+    }
+
+    /// Return a guard giving access to the inner `Vec`, which may be
+    /// emptied during the edit but must not be left empty when the guard
+    /// is dropped, or it panics.
+    ///
+    /// While editing, the vec holds a clone of its first element, which
+    /// is what stays if the guard is leaked or if the edit is ended with
+    /// [`EditGuard::finish`] on an empty vec.
+    #[inline]
+    pub fn edit(&mut self) -> EditGuard<'_, T>
+    where
+        T: Clone,
+    {
+        let placeholder = vec![self.first().clone()];
+        EditGuard {
+            vec: mem::replace(&mut self.vec, placeholder),
+            target: &mut self.vec,
+            panic_if_empty: true,
+            done: false,
+        }
+    }
+
+    /// Return a guard giving access to the inner `Vec`, which may be
+    /// left empty, in which case it's replaced by a vec of `fallback`
+    /// when the guard is dropped.
+    ///
+    /// While editing, the vec holds just `fallback`, which is also what
+    /// stays if the guard is leaked.
+    #[inline]
+    pub fn edit_or(&mut self, fallback: T) -> EditGuard<'_, T> {
+        EditGuard {
+            vec: mem::replace(&mut self.vec, vec![fallback]),
+            target: &mut self.vec,
+            panic_if_empty: false,
+            done: false,
+        }
+    }
+
+    /// Split the vec in two at `at`, keeping the elements before it and
+    /// returning the other ones, unless one of the parts would be empty
+    /// (i.e. `at` is 0 or not less than the length)
+    pub fn try_split_off(&mut self, at: usize) -> Result<NonEmptyVec<T>, NotEnoughElementsError> {
+        if at == 0 || at >= self.vec.len() {
+            return Err(NotEnoughElementsError);
+        }
+        Ok(NonEmptyVec {
+            vec: self.vec.split_off(at),
+        })
+    }
+
+    /// Split the vec in two at `at`, unless the second part would be empty
+    /// (the vec is given back in this case)
+    pub fn split_into(
+        mut self,
+        at: NonZeroUsize,
+    ) -> Result<(NonEmptyVec<T>, NonEmptyVec<T>), Self> {
+        match self.try_split_off(at.get()) {
+            Ok(tail) => Ok((self, tail)),
+            Err(_) => Err(self),
+        }
+    }
+
+    /// Move all the elements of `other` at the end, leaving `other` empty
+    #[inline]
+    pub fn append(&mut self, other: &mut Vec<T>) {
+        self.vec.append(other);
+    }
+
+    /// Move all the elements of `other` at the end.
+    ///
+    /// This never clones. When `self` is the shorter one and `other` has
+    /// enough spare capacity, the buffer of `other` is reused, which saves
+    /// a reallocation and costs a shift of the elements of `other`.
+    pub fn append_non_empty(&mut self, mut other: NonEmptyVec<T>) {
+        let spare = other.vec.capacity() - other.vec.len();
+        if self.vec.len() < other.vec.len() && spare >= self.vec.len() {
+            other.vec.splice(0..0, self.vec.drain(..));
+            self.vec = other.vec;
+        } else {
+            self.vec.append(&mut other.vec);
+        }
+    }
+
+    /// Convert into a boxed slice, dropping the excess capacity
+    /// (which doesn't reallocate when there's none)
+    #[inline]
+    pub fn into_boxed_slice(self) -> NonEmptyBoxedSlice<T> {
+        NonEmptyBoxedSlice {
+            slice: self.vec.into_boxed_slice(),
+        }
+    }
+
+    #[inline]
+    pub fn into_rc_slice(self) -> Rc<[T]> {
+        Rc::from(self.vec)
+    }
+
+    #[inline]
+    pub fn into_arc_slice(self) -> Arc<[T]> {
+        Arc::from(self.vec)
+    }
+}
+
+/// Return the start and end of a range, panicking if it's out of bounds
+/// Convert a range to checked bounds, panicking like std's slicing
+/// when it's out of range
+pub(crate) fn range_to_bounds<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&i) => i,
+        Bound::Excluded(&i) => match i.checked_add(1) {
+            Some(start) => start,
+            None => panic!("attempted to index slice from after maximum usize"),
+        },
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&i) => match i.checked_add(1) {
+            Some(end) => end,
+            None => panic!("attempted to index slice up to maximum usize"),
+        },
+        Bound::Excluded(&i) => i,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "range start {} is after end {}", start, end);
+    assert!(
+        end <= len,
+        "range end {} is out of range for length {}",
+        end,
+        len
+    );
+    (start, end)
+}
+
+impl<A, B> NonEmptyVec<(A, B)> {
+    /// Split a vec of pairs into two vecs of the same length
+    pub fn unzip(self) -> (NonEmptyVec<A>, NonEmptyVec<B>) {
+        let (a, b) = self.vec.into_iter().unzip();
+        (NonEmptyVec { vec: a }, NonEmptyVec { vec: b })
+    }
+}
+
+impl<T> NonEmptyVec<NonEmptyVec<T>> {
+    /// Move the elements of all inner vecs into one vec
+    pub fn flatten(self) -> NonEmptyVec<T> {
+        let len = self.vec.iter().map(|v| v.vec.len()).sum();
+        let mut vec = Vec::with_capacity(len);
+        for inner in self.vec {
+            vec.extend(inner.vec);
+        }
+        NonEmptyVec { vec }
+    }
+}
+
+/// Clone the elements of the non empty sequences into one vec, with
+/// clones of the elements of `sep` between each sequence.
+///
+/// The result isn't checked: `NonEmpty` is sealed, so `seqs[0]` has at
+/// least one element.
+pub(crate) fn join_non_empty<S>(seqs: &[S], sep: &[S::Item]) -> NonEmptyVec<S::Item>
+where
+    S: NonEmpty,
+    S::Item: Clone,
+{
+    let inner_len: usize = seqs.iter().map(|s| s.len().get()).sum();
+    let len = inner_len + sep.len() * seqs.len().saturating_sub(1);
+    let mut vec = Vec::with_capacity(len);
+    for (i, seq) in seqs.iter().enumerate() {
+        if i > 0 {
+            vec.extend_from_slice(sep);
+        }
+        vec.extend(seq.iter().cloned());
+    }
+    NonEmptyVec { vec }
+}
+
+/// Operations on non empty vecs of non empty sequences (e.g.
+/// `NonEmptyVec<NonEmptyVec<T>>` or `NonEmptyVec<NonEmptySlice<T>>`)
+impl<V: NonEmpty> NonEmptyVec<V>
+where
+    V::Item: Clone,
+{
+    /// Clone the elements of all inner sequences into one vec
+    pub fn concat(&self) -> NonEmptyVec<V::Item> {
+        join_non_empty(&self.vec, &[])
+    }
+
+    /// Clone the elements of all inner sequences into one vec, with
+    /// a clone of `sep` between each sequence
+    pub fn join(&self, sep: &V::Item) -> NonEmptyVec<V::Item> {
+        join_non_empty(&self.vec, slice::from_ref(sep))
+    }
+
+    /// Clone the elements of all inner sequences into one vec, with
+    /// clones of the elements of `sep` between each sequence
+    pub fn join_slice(&self, sep: &[V::Item]) -> NonEmptyVec<V::Item> {
+        join_non_empty(&self.vec, sep)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        if vec.is_empty() {
+            Err(NotEnoughElementsError)
+        } else {
+            Ok(Self { vec })
+        }
+    }
+}
+
+impl<T> TryFrom<Box<[T]>> for NonEmptyVec<T> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(slice: Box<[T]>) -> Result<Self, Self::Error> {
+        Self::try_from(slice.into_vec())
+    }
+}
+
+impl<T: Clone> TryFrom<&[T]> for NonEmptyVec<T> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        Self::from_slice(slice)
+    }
+}
+
+impl<T: Clone> TryFrom<&Vec<T>> for NonEmptyVec<T> {
+    type Error = NotEnoughElementsError;
+    #[inline]
+    fn try_from(vec: &Vec<T>) -> Result<Self, Self::Error> {
+        Self::from_slice(vec)
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for Vec<T> {
+    #[inline]
+    fn from(vec: NonEmptyVec<T>) -> Self {
+        vec.vec
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for Box<[T]> {
+    #[inline]
+    fn from(vec: NonEmptyVec<T>) -> Self {
+        vec.vec.into_boxed_slice()
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for Rc<[T]> {
+    #[inline]
+    fn from(vec: NonEmptyVec<T>) -> Self {
+        vec.into_rc_slice()
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for Arc<[T]> {
+    #[inline]
+    fn from(vec: NonEmptyVec<T>) -> Self {
+        vec.into_arc_slice()
+    }
+}
+
+/// Only compiles when the array isn't empty.
+///
+/// As `From<T>` is also implemented, the element type must usually be
+/// given:
+///
+/// ```
+/// let vec = strict::NonEmptyVec::<u8>::from([1, 2, 3]);
+/// assert_eq!(vec.len().get(), 3);
+/// ```
+///
+/// ```compile_fail
+/// let _ = strict::NonEmptyVec::<u8>::from([]);
+/// ```
+impl<T, const N: usize> From<[T; N]> for NonEmptyVec<T> {
+    #[inline]
+    #[allow(clippy::let_unit_value)]
+    fn from(arr: [T; N]) -> Self {
+        let _ = AssertNonEmpty::<N>::OK;
+        Self {
+            vec: Vec::from(arr),
+        }
+    }
+}
+
+/// Parse a comma separated list, see [`NonEmptyVec::parse_delimited`]
+impl<T: FromStr> FromStr for NonEmptyVec<T> {
+    type Err = ParseNonEmptyVecError<T::Err>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_delimited(s, ',')
+    }
+}
+
+impl<T> From<T> for NonEmptyVec<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self { vec: vec![value] }
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T> Deref for NonEmptyVec<T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        self.vec.deref()
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T, I: slice::SliceIndex<[T]>> Index<I> for NonEmptyVec<T> {
+    type Output = I::Output;
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(self.as_slice(), index)
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T, I: slice::SliceIndex<[T]>> IndexMut<I> for NonEmptyVec<T> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(self.as_mut_slice(), index)
+    }
+}
+
+impl<T: PartialEq> PartialEq for NonEmptyVec<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.vec == other.vec
+    }
+}
+
+impl<T: Eq> Eq for NonEmptyVec<T> {}
+
+macro_rules! impl_slice_eq {
+    ([$($gen:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<$($gen)* T: PartialEq<U>, U> PartialEq<$rhs> for $lhs {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                self[..] == other[..]
+            }
+        }
+    };
+}
+
+impl_slice_eq!([] NonEmptyVec<T>, Vec<U>);
+impl_slice_eq!([] NonEmptyVec<T>, [U]);
+impl_slice_eq!(['a,] NonEmptyVec<T>, &'a [U]);
+impl_slice_eq!([const N: usize,] NonEmptyVec<T>, [U; N]);
+impl_slice_eq!([] Vec<T>, NonEmptyVec<U>);
+impl_slice_eq!([][T], NonEmptyVec<U>);
+impl_slice_eq!(['a,] &'a [T], NonEmptyVec<U>);
+impl_slice_eq!([const N: usize,] [T; N], NonEmptyVec<U>);
+
+/// Hash like the equivalent `[T]` and `Vec<T>`
+impl<T: Hash> Hash for NonEmptyVec<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.vec.hash(state);
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for NonEmptyVec<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.vec.partial_cmp(&other.vec)
+    }
+}
+
+impl<T: Ord> Ord for NonEmptyVec<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.vec.cmp(&other.vec)
+    }
+}
+
+impl<'a, T> From<&'a NonEmptyVec<T>> for NonEmptySlice<'a, T> {
+    #[inline]
+    fn from(vec: &'a NonEmptyVec<T>) -> Self {
+        vec.as_non_empty_slice()
+    }
+}
+
+impl<T> AsRef<[T]> for NonEmptyVec<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T> AsMut<[T]> for NonEmptyVec<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.vec
+    }
+}
+
+/// Allow looking up a map keyed by `NonEmptyVec<T>` with a `&[T]`
+impl<T> Borrow<[T]> for NonEmptyVec<T> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T> Extend<T> for NonEmptyVec<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.vec.extend(iter);
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for NonEmptyVec<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.vec.extend(iter);
+    }
+}
+
+/// The iterator returned by [`NonEmptyVec::extract_if`]
+pub struct ExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    /// the last element, followed by the kept ones
+    vec: &'a mut Vec<T>,
+    /// the elements not checked yet, except the last one
+    rest: vec::IntoIter<T>,
+    last_checked: bool,
+    filter: F,
+}
+
+impl<T, F> Iterator for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        // the item is checked while in the vec, so that it's kept if
+        // the filter panics
+        for item in self.rest.by_ref() {
+            self.vec.push(item);
+            let idx = self.vec.len() - 1;
+            // the last element is still in the vec, so this one can go
+            if (self.filter)(&mut self.vec[idx]) {
+                return self.vec.pop();
+            }
+        }
+        if self.last_checked {
+            return None;
+        }
+        let matched = (self.filter)(&mut self.vec[0]);
+        self.last_checked = true;
+        if matched && self.vec.len() > 1 {
+            Some(self.vec.remove(0))
+        } else {
+            self.vec.rotate_left(1);
+            None
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let unchecked = self.rest.len() + usize::from(!self.last_checked);
+        (0, Some(unchecked))
+    }
+}
+
+/// The elements which weren't checked are kept
+impl<T, F> Drop for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        if !self.last_checked {
+            self.vec.extend(self.rest.by_ref());
+            self.vec.rotate_left(1);
+        }
+    }
+}
+
+impl<T, F> FusedIterator for ExtractIf<'_, T, F> where F: FnMut(&mut T) -> bool {}
+
+/// The result of [`NonEmptyVec::partition`], whose variants tell which
+/// sides are non empty
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Partitioned<T> {
+    AllMatched(NonEmptyVec<T>),
+    NoneMatched(NonEmptyVec<T>),
+    Mixed {
+        matched: NonEmptyVec<T>,
+        unmatched: NonEmptyVec<T>,
+    },
+}
+
+impl<T> Partitioned<T> {
+    /// Return the matched and unmatched elements as plain vecs
+    #[inline]
+    pub fn into_vecs(self) -> (Vec<T>, Vec<T>) {
+        match self {
+            Self::AllMatched(matched) => (matched.vec, Vec::new()),
+            Self::NoneMatched(unmatched) => (Vec::new(), unmatched.vec),
+            Self::Mixed { matched, unmatched } => (matched.vec, unmatched.vec),
+        }
+    }
+}
+
+/// A guard giving access to the `Vec` of a [`NonEmptyVec`], returned
+/// by [`NonEmptyVec::edit`] and [`NonEmptyVec::edit_or`]
+pub struct EditGuard<'a, T> {
+    vec: Vec<T>,
+    /// holds a placeholder element until the edit ends
+    target: &'a mut Vec<T>,
+    panic_if_empty: bool,
+    done: bool,
+}
+
+impl<T> EditGuard<'_, T> {
+    /// End the edit, failing if the vec was left empty, in which case
+    /// the placeholder stays (the fallback, or the first element from
+    /// before the edit)
+    #[inline]
+    pub fn finish(mut self) -> Result<(), NotEnoughElementsError> {
+        self.restore()
+    }
+
+    fn restore(&mut self) -> Result<(), NotEnoughElementsError> {
+        self.done = true;
+        if self.vec.is_empty() {
+            Err(NotEnoughElementsError)
+        } else {
+            *self.target = mem::take(&mut self.vec);
+            Ok(())
+        }
+    }
+}
+
+impl<T> Drop for EditGuard<'_, T> {
+    fn drop(&mut self) {
+        if !self.done && self.restore().is_err() && self.panic_if_empty && !thread::panicking() {
+            panic!("the NonEmptyVec was left empty by the edit");
+        }
+    }
+}
+
+impl<T> Deref for EditGuard<'_, T> {
+    type Target = Vec<T>;
+    #[inline]
+    fn deref(&self) -> &Vec<T> {
+        &self.vec
+    }
+}
+
+impl<T> DerefMut for EditGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        &mut self.vec
+    }
+}
+
+/// The owning iterator of a [`NonEmptyVec`]
+#[derive(Debug, Clone)]
+pub struct NonEmptyIntoIter<T> {
+    iter: vec::IntoIter<T>,
+}
+
+impl<T> NonEmptyIntoIter<T> {
+    /// Return the first remaining item and the iterator over the other ones,
+    /// or `None` if the iterator was exhausted with `next` or `next_back`.
+    ///
+    /// Use [`NonEmptyVec::into_first_and_rest`] to get them infallibly.
+    #[inline]
+    pub fn next_first(mut self) -> Option<(T, vec::IntoIter<T>)> {
+        let first = self.iter.next()?;
+        Some((first, self.iter))
+    }
+
+    /// Return the first remaining item, dropping the other ones,
+    /// or `None` if the iterator was exhausted
+    #[inline]
+    pub fn first(self) -> Option<T> {
+        self.next_first().map(|(first, _)| first)
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.iter.as_slice()
+    }
+}
+
+impl<T> Iterator for NonEmptyIntoIter<T> {
+    type Item = T;
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for NonEmptyIntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for NonEmptyIntoIter<T> {}
+
+impl<T> FusedIterator for NonEmptyIntoIter<T> {}
+
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = NonEmptyIntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> NonEmptyIntoIter<T> {
+        NonEmptyIntoIter {
+            iter: self.vec.into_iter(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut NonEmptyVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+    #[inline]
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.vec.iter_mut()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.vec.iter()
+    }
+}
+
+impl<T> crate::non_empty::Sealed for NonEmptyVec<T> {}
+
+impl<T> NonEmpty for NonEmptyVec<T> {
+    type Item = T;
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        T: 'a;
+    #[inline]
+    fn len(&self) -> NonZeroUsize {
+        self.len()
+    }
+    #[inline]
+    fn first(&self) -> &T {
+        self.first()
+    }
+    #[inline]
+    fn last(&self) -> &T {
+        self.last()
+    }
+    #[inline]
+    fn iter(&self) -> slice::Iter<'_, T> {
+        self.vec.iter()
+    }
+}
+
+#[cfg(test)]
+mod non_empty_vec_tests {
+
+    use {super::*, std::convert::TryInto};
+
+    #[test]
+    fn test_pop_push() {
+        let mut vec: NonEmptyVec<usize> = vec![1, 2].try_into().unwrap();
+        assert_eq!(vec.pop(), Some(2));
+        assert_eq!(vec.pop(), None);
+        assert_eq!(vec[0], 1);
+        vec[0] = 0;
+        assert_eq!(*vec.first(), 0);
+        let first: &mut usize = vec.first_mut();
+        *first = 4;
+        assert_eq!(vec[0], 4);
+    }
+
+    #[test]
+    fn test_parse() {
+        let vec: NonEmptyVec<String> = "a".parse().unwrap();
+        assert_eq!(vec.as_slice(), &["a"]);
+        let vec: NonEmptyVec<String> = " a , b,".parse().unwrap();
+        assert_eq!(vec.as_slice(), &["a", "b"]);
+        let vec = NonEmptyVec::<u8>::parse_delimited("1;;2; 3", ';').unwrap();
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+        assert_eq!(
+            "".parse::<NonEmptyVec<String>>().unwrap_err(),
+            ParseNonEmptyVecError::Empty
+        );
+        assert_eq!(
+            " , ,".parse::<NonEmptyVec<String>>().unwrap_err(),
+            ParseNonEmptyVecError::Empty
+        );
+        let err = "1,,x".parse::<NonEmptyVec<u8>>().unwrap_err();
+        assert!(matches!(err, ParseNonEmptyVecError::Item { index: 2, .. }));
+        assert_eq!(
+            err.to_string(),
+            "invalid item at index 2: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let vec = NonEmptyVec::from("a".to_string());
+        let mut iter = vec.clone().into_iter();
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next_back().as_deref(), Some("a"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(vec.clone().into_iter().first().as_deref(), Some("a"));
+        let mut iter = vec.clone().into_iter();
+        iter.next();
+        assert!(iter.clone().first().is_none());
+        assert!(iter.next_first().is_none());
+        let (first, mut rest) = vec.into_first_and_rest();
+        assert_eq!(first, "a");
+        assert_eq!(rest.next(), None);
+        let vec: NonEmptyVec<u8> = vec![1, 2, 3].try_into().unwrap();
+        let mut iter = vec.clone().into_iter();
+        assert_eq!(iter.next_back(), Some(3));
+        let (first, rest) = iter.next_first().unwrap();
+        assert_eq!(first, 1);
+        assert_eq!(rest.as_slice(), &[2]);
+        let (first, rest) = vec.clone().into_first_and_rest();
+        assert_eq!(first, 1);
+        assert_eq!(rest.as_slice(), &[2, 3]);
+        let mut sum = 0;
+        for v in vec {
+            sum += v;
+        }
+        assert_eq!(sum, 6);
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut vec = NonEmptyVec::from(1u8);
+        vec.extend(Vec::<u8>::new());
+        assert_eq!(vec.as_slice(), &[1]);
+        vec.extend(2..4);
+        vec.extend(&[4, 5]);
+        vec.extend_from_slice(&[]);
+        vec.extend_from_slice(&[6]);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(vec.len().get(), 6);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut vec = NonEmptyVec::from(1u8);
+        let mut other = vec![2, 3];
+        vec.append(&mut other);
+        assert!(other.is_empty());
+        vec.append(&mut other);
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+        vec.append_non_empty(NonEmptyVec::from(4));
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+        // the long vec's buffer is reused
+        let mut long = Vec::with_capacity(100);
+        long.extend(5..50);
+        let long = NonEmptyVec::<u8>::try_from(long).unwrap();
+        let ptr = long.as_slice().as_ptr();
+        vec.append_non_empty(long);
+        assert_eq!(vec.as_slice().as_ptr(), ptr);
+        assert_eq!(vec.as_slice(), (1..50).collect::<Vec<u8>>().as_slice());
+    }
+
+    #[test]
+    fn test_map() {
+        let vec = NonEmptyVec::from(3u8);
+        assert_eq!(vec.map_ref(|v| v * 2).as_slice(), &[6]);
+        assert_eq!(vec.map(|v| v.to_string()).as_slice(), &["3"]);
+        let vec: NonEmptyVec<u32> = (0..10_000).collect::<Vec<u32>>().try_into().unwrap();
+        let doubled = vec.map(|v| v as u64 * 2);
+        assert!(doubled.has_len(10_000));
+        assert_eq!(*doubled.last(), 19_998);
+    }
+
+    #[test]
+    fn test_try_map() {
+        let vec: NonEmptyVec<String> = "1,x,3,y".parse().unwrap();
+        let mut calls = 0;
+        let res = vec.try_map_ref(|s| {
+            calls += 1;
+            s.parse::<u32>()
+        });
+        assert!(res.is_err());
+        assert_eq!(calls, 2);
+        let vec: NonEmptyVec<String> = "1,2,3".parse().unwrap();
+        let numbers = vec.try_map(|s| s.parse::<u32>()).unwrap();
+        assert_eq!(numbers.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_macro() {
+        let vec: NonEmptyVec<u8> = nonempty_vec![4];
+        assert_eq!(vec.as_slice(), &[4]);
+        let vec = nonempty_vec![1, 2, 3,];
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+        let vec = nonempty_vec![String::from("a"); 3];
+        assert_eq!(vec.as_slice(), &["a", "a", "a"]);
+        let vec = nonempty_vec![0u64; 1];
+        assert!(vec.has_len(1));
+    }
+
+    #[test]
+    fn test_constructors() {
+        let vec = NonEmptyVec::new('a');
+        assert_eq!(vec.as_slice(), &['a']);
+        let mut vec = NonEmptyVec::with_capacity('a', 10);
+        assert!(vec.vec.capacity() >= 10);
+        let ptr = vec.as_slice().as_ptr();
+        vec.extend("bcdefghij".chars());
+        assert_eq!(vec.as_slice().as_ptr(), ptr);
+        assert!(NonEmptyVec::with_capacity(1u8, 0).vec.capacity() >= 1);
+        let vec = NonEmptyVec::from_first_and_rest("a", vec!["b", "c"]);
+        assert_eq!(vec.as_slice(), &["a", "b", "c"]);
+        assert!(vec.vec.capacity() >= 3);
+        let vec = NonEmptyVec::from_first_and_rest(0u8, None);
+        assert!(vec.has_len(1));
+    }
+
+    #[test]
+    fn test_truncate_resize() {
+        let mut vec = nonempty_vec![1, 2, 3, 4];
+        vec.truncate(NonZeroUsize::new(4).unwrap());
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+        vec.truncate(NonZeroUsize::new(3).unwrap());
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+        vec.truncate(NonZeroUsize::new(1).unwrap());
+        assert_eq!(vec.as_slice(), &[1]);
+        vec.resize(NonZeroUsize::new(3).unwrap(), 0);
+        assert_eq!(vec.as_slice(), &[1, 0, 0]);
+        vec.resize(NonZeroUsize::new(2).unwrap(), 5);
+        assert_eq!(vec.as_slice(), &[1, 0]);
+        vec.push(7);
+        vec.clear_keep_first();
+        assert_eq!(vec.as_slice(), &[1]);
+        vec.clear_keep_first();
+        assert_eq!(vec.as_slice(), &[1]);
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut vec = nonempty_vec![1, 2, 3, 4, 5];
+        let mut calls = 0;
+        let res = vec.try_retain(|&v| {
+            calls += 1;
+            v > 10
+        });
+        assert!(res.is_err());
+        assert_eq!(calls, 5);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4, 5]);
+        assert!(vec.try_retain(|&v| v % 2 == 1).is_ok());
+        assert_eq!(vec.as_slice(), &[1, 3, 5]);
+        vec.retain_or_first(|&v| v > 2);
+        assert_eq!(vec.as_slice(), &[3, 5]);
+        vec.retain_or_first(|&v| v > 10);
+        assert_eq!(vec.as_slice(), &[3]);
+        let mut vec = nonempty_vec![1, 2, 3];
+        assert!(vec
+            .try_retain_mut(|v| {
+                *v *= 10;
+                *v > 15
+            })
+            .is_ok());
+        assert_eq!(vec.as_slice(), &[20, 30]);
+        assert!(vec
+            .try_retain_mut(|v| {
+                *v += 1;
+                false
+            })
+            .is_err());
+        assert_eq!(vec.as_slice(), &[21, 31]);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut vec = nonempty_vec![2; 5];
+        vec.dedup();
+        assert_eq!(vec.as_slice(), &[2]);
+        let mut vec = nonempty_vec![1, 1, 2, 3, 3, 1];
+        vec.dedup();
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 1]);
+        let mut vec = nonempty_vec!["a", "A", "b", "B", "b"];
+        vec.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        assert_eq!(vec.as_slice(), &["a", "b"]);
+        let mut vec = nonempty_vec![10, 11, 20, 35, 39];
+        vec.dedup_by_key(|v| *v / 10);
+        assert_eq!(vec.as_slice(), &[10, 20, 35]);
+    }
+
+    #[test]
+    fn test_split() {
+        let mut vec = nonempty_vec![1];
+        assert_eq!(vec.split_first(), (&1, &[][..]));
+        assert_eq!(vec.split_last(), (&[][..], &1));
+        vec.extend([2, 3]);
+        assert_eq!(vec.split_first(), (&1, &[2, 3][..]));
+        assert_eq!(vec.split_last(), (&[1, 2][..], &3));
+        let (first, rest) = vec.split_first_mut();
+        for v in rest {
+            *v += *first;
+        }
+        *first = 0;
+        assert_eq!(vec.as_slice(), &[0, 3, 4]);
+        let (init, last) = vec.split_last_mut();
+        init[0] = *last;
+        *last = 5;
+        assert_eq!(vec.as_slice(), &[4, 3, 5]);
+    }
+
+    #[test]
+    fn test_into_vec() {
+        let vec = nonempty_vec!['a', 'b', 'c'];
+        assert_eq!(vec.clone().take(), 'a');
+        assert_eq!(vec.clone().into_first_rest(), ('a', vec!['b', 'c']));
+        assert_eq!(NonEmptyVec::new(1).into_first_rest(), (1, vec![]));
+        assert_eq!(vec.clone().into_vec(), vec!['a', 'b', 'c']);
+        let vec: Vec<char> = vec.into();
+        assert_eq!(vec.len(), 3);
+    }
+
+    #[test]
+    fn test_comparisons() {
+        use std::collections::{hash_map::DefaultHasher, HashMap};
+        let mut map = HashMap::new();
+        map.insert(nonempty_vec!["a".to_string()], 1);
+        map.insert(nonempty_vec!["a".to_string(), "b".to_string()], 2);
+        assert_eq!(map.get(&nonempty_vec!["a".to_string()]), Some(&1));
+        let hash = |v: &dyn Fn(&mut DefaultHasher)| {
+            let mut hasher = DefaultHasher::new();
+            v(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(
+            hash(&|h| nonempty_vec![1, 2].hash(h)),
+            hash(&|h| [1, 2][..].hash(h)),
+        );
+        let mut vecs = vec![nonempty_vec![2u32], nonempty_vec![1, 5], nonempty_vec![1]];
+        vecs.sort();
+        assert_eq!(
+            vecs,
+            vec![nonempty_vec![1], nonempty_vec![1, 5], nonempty_vec![2]]
+        );
+        assert!(nonempty_vec![1.0] < nonempty_vec![1.5]);
+    }
+
+    #[test]
+    fn test_cross_type() {
+        use std::collections::HashMap;
+        let vec = nonempty_vec![1, 2, 3];
+        assert_eq!(vec, vec![1, 2, 3]);
+        assert_eq!(vec![1, 2, 3], vec);
+        assert_eq!(vec, [1, 2, 3]);
+        assert_eq!([1, 2, 3], vec);
+        assert_eq!(vec, &[1, 2, 3][..]);
+        assert_eq!(&[1, 2, 3][..], vec);
+        assert_eq!(vec, *[1, 2, 3].as_slice());
+        assert_ne!(vec, [1, 2]);
+        let mut map = HashMap::new();
+        map.insert(nonempty_vec!['a', 'b'], "ab");
+        let key: &[char] = &['a', 'b'];
+        assert_eq!(map.get(key), Some(&"ab"));
+        let mut vec = vec;
+        vec.as_mut()[0] = 0;
+        let slice: &[i32] = vec.as_ref();
+        assert_eq!(slice, &[0, 2, 3]);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let vec = &nonempty_vec![(2, 'a')];
+        assert_eq!(vec.max_elem(), &(2, 'a'));
+        assert_eq!(vec.min_by_key(|v| v.1), &(2, 'a'));
+        let vec = &nonempty_vec![(1, 'a'), (3, 'b'), (1, 'c'), (3, 'd')];
+        assert_eq!(vec.max_elem(), &(3, 'd'));
+        assert_eq!(vec.min_elem(), &(1, 'a'));
+        assert_eq!(vec.max_by_key(|v| v.0), &(3, 'd'));
+        assert_eq!(vec.min_by_key(|v| v.0), &(1, 'a'));
+        assert_eq!(vec.max_by(|a, b| a.0.cmp(&b.0)), &(3, 'd'));
+        assert_eq!(vec.min_by(|a, b| a.0.cmp(&b.0)), &(1, 'a'));
+        assert_eq!(vec.iter_ne().max_by_key(|v| v.1), &(3, 'd'));
+    }
+
+    #[test]
+    fn test_reduce() {
+        let vec = nonempty_vec!["a".to_string()];
+        assert_eq!(vec.clone().reduce(|a, b| a + &b), "a");
+        assert_eq!(vec.fold_first(|s| s.len(), |n, s| n + s.len()), 1);
+        let vec = nonempty_vec!["a", "b", "c"];
+        assert_eq!(vec.fold_first(|s| s.to_string(), |acc, s| acc + s), "abc");
+        let vec = nonempty_vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        assert_eq!(vec.reduce(|a, b| format!("({}{})", a, b)), "((ab)c)");
+    }
+
+    #[test]
+    fn test_flatten_concat_join() {
+        let nested = nonempty_vec![nonempty_vec![1, 2]];
+        assert_eq!(nested.concat(), [1, 2]);
+        assert_eq!(nested.join(&0), [1, 2]);
+        assert_eq!(nested.flatten(), [1, 2]);
+        let nested = nonempty_vec![nonempty_vec![1], nonempty_vec![2], nonempty_vec![3, 4]];
+        assert_eq!(nested.concat(), [1, 2, 3, 4]);
+        assert_eq!(nested.join(&0), [1, 0, 2, 0, 3, 4]);
+        assert_eq!(nested.join_slice(&[8, 9]), [1, 8, 9, 2, 8, 9, 3, 4]);
+        let flat = nested.flatten();
+        assert_eq!(flat, [1, 2, 3, 4]);
+        let slices = nonempty_vec![
+            NonEmptySlice::from_array(&["a", "b"]),
+            NonEmptySlice::from_array(&["c"]),
+        ];
+        assert_eq!(slices.join(&"-"), ["a", "b", "-", "c"]);
+        assert!(slices.concat().vec.capacity() >= 3);
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut vec = nonempty_vec![1, 2, 3, 4, 5];
+        assert!(vec.try_drain(..).is_err());
+        assert!(vec.try_drain(0..5).is_err());
+        assert_eq!(vec.try_drain(2..2).unwrap().count(), 0);
+        assert_eq!(vec.try_drain(0..0).unwrap().count(), 0);
+        assert_eq!(vec, [1, 2, 3, 4, 5]);
+        assert_eq!(vec.try_drain(1..3).unwrap().collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(vec, [1, 4, 5]);
+        assert_eq!(vec.try_drain(..=1).unwrap().collect::<Vec<_>>(), [1, 4]);
+        assert_eq!(vec, [5]);
+        // not consuming the iterator still removes the elements
+        let mut vec = nonempty_vec![1, 2, 3, 4];
+        drop(vec.try_drain(..2).unwrap());
+        assert_eq!(vec, [3, 4]);
+        // leaking the iterator can't empty the vec
+        let mut vec = nonempty_vec![1, 2, 3, 4];
+        std::mem::forget(vec.try_drain(..3).unwrap());
+        assert!(vec.len().get() >= 1);
+        assert_eq!(vec.first(), &4);
+        let mut vec = nonempty_vec![1, 2, 3, 4];
+        std::mem::forget(vec.try_drain(2..).unwrap());
+        assert_eq!(vec, [1, 2]);
+    }
+
+    #[test]
+    fn test_chunks() {
+        let vec = nonempty_vec![1, 2, 3];
+        let three = NonZeroUsize::new(3).unwrap();
+        assert_eq!(vec.chunks(three).first().as_slice(), &[1, 2, 3]);
+        assert_eq!(
+            vec.rchunks(NonZeroUsize::new(2).unwrap()).last().as_slice(),
+            &[1]
+        );
+        assert_eq!(vec.chunks_exact(three).len(), 1);
+    }
+
+    #[test]
+    fn test_chunk_by() {
+        let vec = nonempty_vec!['a', 'a', 'b', 'a'];
+        let runs: Vec<_> = vec.chunk_by(|a, b| a == b).into_iter().collect();
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].as_slice(), &['a', 'a']);
+        assert_eq!(vec.chunk_by(|_, _| true).first().as_slice(), vec.as_slice());
+    }
+
+    #[test]
+    fn test_zip() {
+        let pairs = nonempty_vec![1, 2, 3].zip(nonempty_vec!['a', 'b']);
+        assert_eq!(pairs, [(1, 'a'), (2, 'b')]);
+        let (numbers, letters) = pairs.unzip();
+        assert_eq!(numbers, [1, 2]);
+        assert_eq!(letters, ['a', 'b']);
+        let sums = nonempty_vec![1].zip_with(nonempty_vec![10, 20], |a, b| a + b);
+        assert_eq!(sums, [11]);
+        let (a, b) = nonempty_vec![(1, 'x')].unzip();
+        assert_eq!((a, b), (nonempty_vec![1], nonempty_vec!['x']));
+    }
+
+    #[test]
+    fn test_repeat() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let many = NonZeroUsize::new(1000).unwrap();
+        assert_eq!(NonEmptyVec::from_elem('a', one), ['a']);
+        let vec = NonEmptyVec::from_elem(0u8, many);
+        assert!(vec.has_len(1000));
+        assert_eq!(vec.vec.capacity(), 1000);
+        let vec = nonempty_vec![1, 2, 3];
+        assert_eq!(vec.repeat(one), vec);
+        let repeated = vec.repeat(many);
+        assert!(repeated.has_len(3000));
+        assert_eq!(repeated.vec.capacity(), 3000);
+        assert_eq!(repeated[2997..], [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut vec = nonempty_vec![1, 2, 3, 4];
+        assert!(vec.try_split_off(0).is_err());
+        assert!(vec.try_split_off(4).is_err());
+        assert!(vec.try_split_off(5).is_err());
+        assert_eq!(vec.try_split_off(3).unwrap(), [4]);
+        assert_eq!(vec, [1, 2, 3]);
+        assert_eq!(vec.try_split_off(1).unwrap(), [2, 3]);
+        assert_eq!(vec, [1]);
+        assert!(vec.try_split_off(1).is_err());
+        let vec = nonempty_vec!['a', 'b', 'c'];
+        let at = |i| NonZeroUsize::new(i).unwrap();
+        let (head, tail) = vec.clone().split_into(at(1)).unwrap();
+        assert_eq!((head, tail), (nonempty_vec!['a'], nonempty_vec!['b', 'c']));
+        let (head, tail) = vec.clone().split_into(at(2)).unwrap();
+        assert_eq!((head, tail), (nonempty_vec!['a', 'b'], nonempty_vec!['c']));
+        assert_eq!(vec.clone().split_into(at(3)).unwrap_err(), vec);
+    }
+
+    #[test]
+    fn test_try_insert_remove() {
+        let mut vec = nonempty_vec!["b".to_string()];
+        assert_eq!(vec.try_insert(0, "a".to_string()), Ok(()));
+        assert_eq!(vec.try_insert(2, "c".to_string()), Ok(()));
+        let (value, err) = vec.try_insert(4, "e".to_string()).unwrap_err();
+        assert_eq!(value, "e");
+        assert_eq!(err, StrictVecError::IndexOutOfBounds { idx: 4, len: 3 });
+        assert_eq!(err.to_string(), "index 4 is out of bounds for length 3");
+        assert_eq!(
+            vec.try_remove(3),
+            Err(StrictVecError::IndexOutOfBounds { idx: 3, len: 3 }),
+        );
+        assert_eq!(vec, ["a", "b", "c"]);
+        assert_eq!(vec.try_remove(1).unwrap(), "b");
+        assert_eq!(vec.try_remove(0).unwrap(), "a");
+        assert_eq!(vec.try_remove(0), Err(StrictVecError::WouldBecomeEmpty));
+        assert_eq!(vec, ["c"]);
+    }
+
+    #[test]
+    fn test_get_disjoint_mut() {
+        let mut vec = nonempty_vec![1, 2, 3, 4];
+        assert!(vec.get_two_mut(1, 1).is_none());
+        assert!(vec.get_two_mut(1, 4).is_none());
+        let (a, b) = vec.get_two_mut(1, 2).unwrap();
+        std::mem::swap(a, b);
+        assert_eq!(vec, [1, 3, 2, 4]);
+        let (a, b) = vec.get_two_mut(3, 0).unwrap();
+        assert_eq!((*a, *b), (4, 1));
+        *a += *b;
+        assert_eq!(vec, [1, 3, 2, 5]);
+        assert!(vec.get_disjoint_mut([0, 2, 0]).is_none());
+        assert!(vec.get_disjoint_mut([0, 4]).is_none());
+        let [a, b, c] = vec.get_disjoint_mut([3, 0, 1]).unwrap();
+        assert_eq!((*a, *b, *c), (5, 1, 3));
+        *a = 0;
+        *c = 9;
+        assert_eq!(vec, [1, 9, 2, 0]);
+        assert!(vec.get_disjoint_mut([]).is_some());
+        let mut one = nonempty_vec![1];
+        assert!(one.get_two_mut(0, 0).is_none());
+    }
+
+    #[test]
+    fn test_into_sorted() {
+        assert_eq!(
+            nonempty_vec![3, 1, 2].into_sorted().into_vec(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(nonempty_vec![3, 1, 2].into_sorted_unstable(), [1, 2, 3]);
+        let vec = nonempty_vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+        assert_eq!(
+            vec.clone().into_sorted_by_key(|p| p.0),
+            [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')],
+        );
+        assert_eq!(
+            vec.clone().into_sorted_by(|a, b| b.0.cmp(&a.0)),
+            [(2, 'a'), (2, 'c'), (1, 'b'), (1, 'd')],
+        );
+        assert_eq!(vec.clone().into_sorted_unstable_by_key(|p| p.1), vec);
+        assert_eq!(
+            vec.clone().into_sorted_unstable_by(|a, b| a.1.cmp(&b.1)),
+            vec
+        );
+    }
+
+    #[test]
+    fn test_non_empty_slice() {
+        fn total(numbers: NonEmptySlice<'_, u32>) -> u32 {
+            numbers.iter().sum()
+        }
+        let vec = nonempty_vec![1, 2, 3];
+        let slice = {
+            let slice = vec.as_non_empty_slice();
+            assert_eq!(total(slice), 6);
+            slice
+        };
+        assert_eq!(total((&vec).into()), 6);
+        assert_eq!(slice.first(), &1);
+        assert_eq!(slice.to_non_empty_vec(), vec);
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let empty: &[u8] = &[];
+        assert!(NonEmptyVec::from_slice(empty).is_err());
+        assert!(NonEmptyVec::<u8>::try_from(&Vec::new()).is_err());
+        let one = NonEmptyVec::<u8>::try_from(&[7][..]).unwrap();
+        assert_eq!(one, [7]);
+        let names = vec!["Ann".to_string(), "Bob".to_string()];
+        let vec = NonEmptyVec::<String>::try_from(&names).unwrap();
+        assert_eq!(vec, names);
+        assert_eq!(vec.vec.capacity(), 2);
+    }
+
+    #[test]
+    fn test_from_array() {
+        let vec = NonEmptyVec::<u8>::from([4; 1]);
+        assert_eq!(vec.len().get(), 1);
+        assert_eq!(vec.first(), &4);
+        let vec: NonEmptyVec<String> = ["a".to_string(), "b".to_string()].into();
+        assert_eq!(vec, ["a", "b"]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[allow(unsafe_code)]
+    fn test_new_unchecked() {
+        let vec = unsafe { NonEmptyVec::new_unchecked(vec!['a']) };
+        assert_eq!(vec.first(), &'a');
+        let slice = unsafe { NonEmptySlice::new_unchecked(vec.as_slice()) };
+        assert_eq!(slice.len().get(), 1);
+        assert_eq!(slice.last(), &'a');
+    }
+
+    #[test]
+    fn test_display_join() {
+        let vec = nonempty_vec![3, 14, 15];
+        assert_eq!(vec.display_join("-").to_string(), "3-14-15");
+        assert_eq!(format!("{:02}", vec.display_join("-")), "03-14-15");
+        let vec = nonempty_vec!['a', 'b'];
+        assert_eq!(
+            vec.display_join_with_last(", ", " or ").to_string(),
+            "a or b"
+        );
+        assert_eq!(nonempty_vec!["x"].display_join(", ").to_string(), "x");
+    }
+
+    #[test]
+    fn test_chunk_arrays() {
+        let mut vec = nonempty_vec![1, 2, 3];
+        assert_eq!(vec.first_array1(), &[1]);
+        vec.first_array1_mut()[0] = 0;
+        assert_eq!(vec.first_chunk::<2>(), Some(&[0, 2]));
+        assert_eq!(vec.first_chunk::<3>(), Some(&[0, 2, 3]));
+        assert_eq!(vec.first_chunk::<4>(), None);
+        assert_eq!(vec.last_chunk::<2>(), Some(&[2, 3]));
+        assert_eq!(vec.last_chunk::<3>(), Some(&[0, 2, 3]));
+        assert_eq!(vec.last_chunk::<4>(), None);
+        if let Some([a, b]) = vec.last_chunk_mut() {
+            std::mem::swap(a, b);
+        }
+        if let Some([a]) = vec.first_chunk_mut() {
+            *a = 5;
+        }
+        assert_eq!(vec, [5, 3, 2]);
+    }
+
+    #[test]
+    fn test_try_splice() {
+        let mut vec = nonempty_vec![1, 2, 3, 4];
+        assert_eq!(vec.try_splice(1..3, [7, 8, 9]).ok(), Some(vec![2, 3]));
+        assert_eq!(vec, [1, 7, 8, 9, 4]);
+        assert_eq!(vec.try_splice(..4, None).ok(), Some(vec![1, 7, 8, 9]));
+        assert_eq!(vec, [4]);
+        assert!(vec.try_splice(.., None).is_err());
+        assert!(vec.try_splice(0..1, Vec::new()).is_err());
+        assert_eq!(vec, [4]);
+        assert_eq!(vec.try_splice(.., [5, 6]).ok(), Some(vec![4]));
+        assert_eq!(vec, [5, 6]);
+        assert_eq!(vec.try_splice(2.., [7]).ok(), Some(vec![]));
+        assert_eq!(vec, [5, 6, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "maximum usize")]
+    fn test_try_splice_max_range() {
+        let mut vec = nonempty_vec![1, 2, 3];
+        let _ = vec.try_splice(..=usize::MAX, vec![9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "maximum usize")]
+    fn test_try_drain_max_range() {
+        let mut vec = nonempty_vec![1, 2, 3];
+        let _ = vec.try_drain(..=usize::MAX);
+    }
+
+    #[test]
+    fn test_extract_if() {
+        let mut vec = nonempty_vec![1, 2, 3, 4, 5, 6];
+        let even: Vec<_> = vec.extract_if(|v| *v % 2 == 0).collect();
+        assert_eq!(even, [2, 4, 6]);
+        assert_eq!(vec, [1, 3, 5]);
+        assert_eq!(vec.extract_if(|v| *v > 10).next(), None);
+        assert_eq!(vec, [1, 3, 5]);
+        let all: Vec<_> = vec.extract_if(|_| true).collect();
+        assert_eq!(all, [1, 3]);
+        assert_eq!(vec, [5]);
+        let mut vec = nonempty_vec![1, 2, 3, 4, 5, 6];
+        {
+            let mut iter = vec.extract_if(|v| {
+                *v *= 10;
+                *v > 20
+            });
+            assert_eq!(iter.next(), Some(30));
+        }
+        assert_eq!(vec, [10, 20, 4, 5, 6]);
+        let mut vec = nonempty_vec!['a', 'b'];
+        assert_eq!(vec.extract_if(|_| false).size_hint(), (0, Some(2)));
+        assert_eq!(vec.extract_if(|&mut c| c == 'b').next(), Some('b'));
+        assert_eq!(vec, ['a']);
+    }
+
+    #[test]
+    fn test_extract_if_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        let mut vec = nonempty_vec![1, 2, 3, 4];
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            vec.extract_if(|v| {
+                assert_ne!(*v, 2);
+                *v == 1
+            })
+            .count()
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec, [2, 3, 4]);
+        let mut vec = nonempty_vec![1, 2, 3, 4];
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            vec.extract_if(|v| {
+                assert_ne!(*v, 4);
+                *v == 2
+            })
+            .count()
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec, [1, 3, 4]);
+    }
+
+    #[test]
+    fn test_extract_if_all() {
+        let len = 200_000;
+        let mut vec = NonEmptyVec::from_first_and_rest(0, 1..len);
+        assert_eq!(vec.extract_if(|_| true).count(), len - 1);
+        assert_eq!(vec, [len - 1]);
+        let mut vec = NonEmptyVec::from_first_and_rest(0, 1..len);
+        let odd = vec.extract_if(|v| *v % 2 == 1).count();
+        assert_eq!(odd, len / 2);
+        assert!(vec.iter().enumerate().all(|(i, v)| *v == i * 2));
+    }
+
+    #[test]
+    fn test_split_at() {
+        let vec = nonempty_vec!['a', 'b', 'c', 'd'];
+        assert!(vec.split_at_non_empty(0).is_err());
+        assert!(vec.split_at_non_empty(4).is_err());
+        let (left, right) = vec.split_at_non_empty(3).unwrap();
+        assert_eq!(left.last(), &'c');
+        assert_eq!(right.first(), &'d');
+        let (head, rest) = vec.split_at(2);
+        assert_eq!(head.as_slice(), &['a', 'b']);
+        assert_eq!(rest, &['c', 'd']);
+    }
+
+    #[test]
+    fn test_pop_many() {
+        let mut vec = nonempty_vec![1, 2, 3, 4, 5];
+        assert!(vec.pop_many(0).is_empty());
+        assert_eq!(vec.pop_many(2), [4, 5]);
+        assert_eq!(vec, [1, 2, 3]);
+        assert_eq!(vec.pop_many(10), [2, 3]);
+        assert_eq!(vec, [1]);
+        assert!(vec.pop_many(1).is_empty());
+        let mut vec = nonempty_vec![1, 2, 3, 4, 5];
+        let len = NonZeroUsize::new(2).unwrap();
+        assert_eq!(vec.truncate_back_to(len), [3, 4, 5]);
+        assert!(vec.truncate_back_to(len).is_empty());
+        assert_eq!(vec, [1, 2]);
+    }
+
+    #[test]
+    fn test_edit() {
+        let mut vec = nonempty_vec![3, 1, 2];
+        {
+            let mut edit = vec.edit();
+            edit.clear();
+            edit.extend([5, 4]);
+        }
+        assert_eq!(vec, [5, 4]);
+        let mut edit = vec.edit();
+        edit.clear();
+        assert!(edit.finish().is_err());
+        assert_eq!(vec, [5]);
+        let mut edit = vec.edit_or(0);
+        edit.push(6);
+        assert!(edit.finish().is_ok());
+        assert_eq!(vec, [5, 6]);
+        vec.edit_or(0).clear();
+        assert_eq!(vec, [0]);
+        std::mem::forget(vec.edit_or(7));
+        assert_eq!(vec, [7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "left empty")]
+    fn test_edit_left_empty() {
+        let mut vec = nonempty_vec!["a"];
+        vec.edit().retain(|s| s.is_empty());
+    }
+
+    #[test]
+    fn test_partition() {
+        let vec = nonempty_vec![1, 2, 3, 4, 5];
+        match vec.clone().partition(|v| v % 2 == 0) {
+            Partitioned::Mixed { matched, unmatched } => {
+                assert_eq!(matched, [2, 4]);
+                assert_eq!(unmatched, [1, 3, 5]);
+            }
+            p => panic!("unexpected {:?}", p),
+        }
+        assert_eq!(
+            vec.clone().partition(|&v| v > 0),
+            Partitioned::AllMatched(vec.clone())
+        );
+        assert_eq!(
+            vec.clone().partition(|&v| v > 5),
+            Partitioned::NoneMatched(vec.clone())
+        );
+        let one = nonempty_vec!['a'];
+        assert_eq!(
+            one.clone().partition(|c| c.is_alphabetic()),
+            Partitioned::AllMatched(one.clone())
+        );
+        assert_eq!(
+            one.partition(|c| c.is_numeric()).into_vecs(),
+            (vec![], vec!['a'])
+        );
+    }
+
+    #[test]
+    fn test_shared_slices() {
+        let vec: NonEmptyVec<u8> = vec![1, 2, 3].try_into().unwrap();
+        let boxed: Box<[u8]> = vec.clone().into();
+        assert_eq!(&*boxed, &[1, 2, 3]);
+        assert_eq!(
+            NonEmptyVec::<u8>::try_from(boxed).unwrap().as_slice(),
+            &[1, 2, 3]
+        );
+        let empty: Box<[u8]> = Box::new([]);
+        assert!(NonEmptyVec::<u8>::try_from(empty).is_err());
+        let rc: Rc<[u8]> = vec.clone().into();
+        assert_eq!(&*rc, vec.as_slice());
+        let arc = vec.clone().into_arc_slice();
+        assert_eq!(&*arc, vec.as_slice());
+        assert_eq!(Arc::<[u8]>::from(vec).len(), 3);
     }
 }
@@ -0,0 +1,301 @@
+use {
+    crate::{
+        few_iter::{Few, FewIter},
+        NonEmpty, NonEmptyIter, OneToThree,
+    },
+    std::{convert::TryFrom, fmt, hash, num::NonZeroUsize},
+};
+
+/// Error returned when building a [`OneOrTwo`] from a sequence which
+/// doesn't have 1 or 2 elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OneOrTwoError {
+    Empty,
+    /// There are this number of elements, more than 2
+    TooMany(usize),
+}
+
+impl fmt::Display for OneOrTwoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no element"),
+            Self::TooMany(len) => write!(f, "{} elements, expected at most 2", len),
+        }
+    }
+}
+
+impl std::error::Error for OneOrTwoError {}
+
+/// An ordered set of 1 or 2 elements, allowing pattern matching.
+///
+/// Implements Copy, Clone, PartialEq, Eq, Debug, etc. if the element type does.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OneOrTwo<T> {
+    One(T),
+    Two(T, T),
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl<T> OneOrTwo<T> {
+    pub fn one(a: T) -> Self {
+        Self::One(a)
+    }
+    pub fn two(a: T, b: T) -> Self {
+        Self::Two(a, b)
+    }
+    pub fn len(&self) -> usize {
+        match self {
+            Self::One(_) => 1,
+            Self::Two(_, _) => 2,
+        }
+    }
+    pub fn iter(&self) -> OneOrTwoIter<'_, T> {
+        OneOrTwoIter::new(self)
+    }
+    /// an iterator keeping the knowledge that there's at least one element
+    pub fn iter_ne(&self) -> NonEmptyIter<OneOrTwoIter<'_, T>> {
+        NonEmptyIter::new(self.iter())
+    }
+    pub fn first(&self) -> &T {
+        match self {
+            Self::One(f) => f,
+            Self::Two(f, _) => f,
+        }
+    }
+    pub fn first_mut(&mut self) -> &mut T {
+        match self {
+            Self::One(ref mut f) => f,
+            Self::Two(ref mut f, _) => f,
+        }
+    }
+    /// the last element, which is also the first one when there's only one
+    pub fn last(&self) -> &T {
+        match self {
+            Self::One(l) => l,
+            Self::Two(_, l) => l,
+        }
+    }
+    pub fn last_mut(&mut self) -> &mut T {
+        match self {
+            Self::One(ref mut l) => l,
+            Self::Two(_, ref mut l) => l,
+        }
+    }
+    pub fn get(&self, i: usize) -> Option<&T> {
+        match (i, self) {
+            (0, _) => Some(self.first()),
+            (1, Self::Two(_, b)) => Some(b),
+            _ => None,
+        }
+    }
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        match (i, self) {
+            (0, Self::One(ref mut a)) => Some(a),
+            (0, Self::Two(ref mut a, _)) => Some(a),
+            (1, Self::Two(_, ref mut b)) => Some(b),
+            _ => None,
+        }
+    }
+    pub fn to_vec(self) -> Vec<T> {
+        match self {
+            Self::One(a) => vec![a],
+            Self::Two(a, b) => vec![a, b],
+        }
+    }
+    pub fn to_ref_vec(&self) -> Vec<&T> {
+        match self {
+            Self::One(a) => vec![a],
+            Self::Two(a, b) => vec![a, b],
+        }
+    }
+    pub fn sorted(self) -> Self
+    where
+        T: PartialOrd,
+    {
+        match self {
+            Self::One(a) => Self::One(a),
+            Self::Two(a, b) => {
+                if a < b {
+                    Self::Two(a, b)
+                } else {
+                    Self::Two(b, a)
+                }
+            }
+        }
+    }
+    pub fn map<B, F>(self, f: F) -> OneOrTwo<B>
+    where
+        F: Fn(T) -> B,
+    {
+        match self {
+            Self::One(a) => OneOrTwo::One(f(a)),
+            Self::Two(a, b) => OneOrTwo::Two(f(a), f(b)),
+        }
+    }
+    pub fn try_map<B, E, F>(self, f: F) -> Result<OneOrTwo<B>, E>
+    where
+        F: Fn(T) -> Result<B, E>,
+    {
+        Ok(match self {
+            Self::One(a) => OneOrTwo::One(f(a)?),
+            Self::Two(a, b) => OneOrTwo::Two(f(a)?, f(b)?),
+        })
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for OneOrTwo<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let v = self.to_ref_vec();
+        f.debug_list().entries(v).finish()
+    }
+}
+
+impl<T: hash::Hash> hash::Hash for OneOrTwo<T> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        for e in self.iter() {
+            e.hash(state);
+        }
+    }
+}
+
+pub type OneOrTwoIter<'i, T> = FewIter<'i, OneOrTwo<T>>;
+
+impl<T> Few for OneOrTwo<T> {
+    type Item = T;
+    fn get(&self, i: usize) -> Option<&T> {
+        self.get(i)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a OneOrTwo<T> {
+    type Item = &'a T;
+    type IntoIter = OneOrTwoIter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> OneOrTwoIter<'a, T> {
+        self.iter()
+    }
+}
+
+/// Fails if the vec is empty or has more than 2 elements
+impl<T> TryFrom<Vec<T>> for OneOrTwo<T> {
+    type Error = OneOrTwoError;
+    fn try_from(mut v: Vec<T>) -> Result<Self, Self::Error> {
+        if v.len() > 2 {
+            return Err(OneOrTwoError::TooMany(v.len()));
+        }
+        let b = v.pop().ok_or(OneOrTwoError::Empty)?;
+        if let Some(a) = v.pop() {
+            Ok(Self::Two(a, b))
+        } else {
+            Ok(Self::One(b))
+        }
+    }
+}
+
+impl<T> From<T> for OneOrTwo<T> {
+    fn from(a: T) -> Self {
+        Self::One(a)
+    }
+}
+impl<T> From<(T, T)> for OneOrTwo<T> {
+    fn from(t: (T, T)) -> Self {
+        Self::Two(t.0, t.1)
+    }
+}
+
+impl<T> From<OneOrTwo<T>> for OneToThree<T> {
+    fn from(few: OneOrTwo<T>) -> Self {
+        match few {
+            OneOrTwo::One(a) => Self::One(a),
+            OneOrTwo::Two(a, b) => Self::Two(a, b),
+        }
+    }
+}
+
+/// Fail, giving back the value, when there are three elements
+impl<T> TryFrom<OneToThree<T>> for OneOrTwo<T> {
+    type Error = OneToThree<T>;
+    fn try_from(few: OneToThree<T>) -> Result<Self, Self::Error> {
+        match few {
+            OneToThree::One(a) => Ok(Self::One(a)),
+            OneToThree::Two(a, b) => Ok(Self::Two(a, b)),
+            three => Err(three),
+        }
+    }
+}
+
+impl<T> crate::non_empty::Sealed for OneOrTwo<T> {}
+
+impl<T> NonEmpty for OneOrTwo<T> {
+    type Item = T;
+    type Iter<'a>
+        = OneOrTwoIter<'a, T>
+    where
+        T: 'a;
+    #[inline]
+    fn len(&self) -> NonZeroUsize {
+        match NonZeroUsize::new(self.len()) {
+            Some(len) => len,
+            None => unreachable!(),
+        }
+    }
+    #[inline]
+    fn first(&self) -> &T {
+        self.first()
+    }
+    #[inline]
+    fn last(&self) -> &T {
+        self.last()
+    }
+    #[inline]
+    fn iter(&self) -> OneOrTwoIter<'_, T> {
+        self.iter()
+    }
+}
+
+#[test]
+fn test_one_or_two_accessors() {
+    let one = OneOrTwo::one("a");
+    assert_eq!(one.len(), 1);
+    assert_eq!(one.first(), one.last());
+    assert_eq!(one.get(1), None);
+    let mut two = OneOrTwo::two("a", "b");
+    assert_eq!(*two.last(), "b");
+    assert_eq!(two.get(1), Some(&"b"));
+    *two.last_mut() = "c";
+    assert_eq!(two.iter().copied().collect::<Vec<_>>(), vec!["a", "c"]);
+    assert_eq!(two.to_vec(), vec!["a", "c"]);
+}
+
+#[test]
+fn test_one_or_two_sort_and_map() {
+    assert_eq!(OneOrTwo::two(5, 2).sorted(), OneOrTwo::two(2, 5));
+    assert_eq!(OneOrTwo::one(5).sorted(), OneOrTwo::one(5));
+    assert_eq!(OneOrTwo::two(1, 2).map(|x| x * 10), OneOrTwo::two(10, 20));
+    assert_eq!(
+        OneOrTwo::two("1", "2").try_map(|x| x.parse()),
+        Ok(OneOrTwo::two(1, 2)),
+    );
+    assert!(OneOrTwo::one("a").try_map(|x| x.parse::<u8>()).is_err());
+}
+
+#[test]
+fn test_one_or_two_conversions() {
+    assert_eq!(OneOrTwo::<u8>::try_from(vec![]), Err(OneOrTwoError::Empty));
+    assert_eq!(OneOrTwo::try_from(vec![1]), Ok(OneOrTwo::one(1)));
+    assert_eq!(OneOrTwo::try_from(vec![1, 2]), Ok(OneOrTwo::two(1, 2)));
+    assert_eq!(
+        OneOrTwo::<u8>::try_from(vec![1, 2, 3]),
+        Err(OneOrTwoError::TooMany(3))
+    );
+    assert_eq!(
+        OneOrTwoError::TooMany(3).to_string(),
+        "3 elements, expected at most 2"
+    );
+    assert_eq!(OneOrTwo::from((1, 2)), OneOrTwo::two(1, 2));
+    let three: OneToThree<u8> = OneOrTwo::two(1, 2).into();
+    assert_eq!(three, OneToThree::two(1, 2));
+    assert_eq!(OneOrTwo::try_from(three), Ok(OneOrTwo::two(1, 2)));
+    let three = OneToThree::three(1, 2, 3);
+    assert_eq!(OneOrTwo::<u8>::try_from(three), Err(three));
+}
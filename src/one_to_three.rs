@@ -1,26 +1,69 @@
-use std::{fmt, hash};
+use {
+    crate::{
+        few_iter::{Few, FewIter},
+        NonEmpty, NonEmptyIter,
+    },
+    std::{cmp::Ordering, fmt, hash, iter::FusedIterator, mem, num::NonZeroUsize, sync::Arc},
+};
+
+/// Error returned when building a [`OneToThree`] from a sequence which
+/// doesn't have 1 to 3 elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OneToThreeError {
+    Empty,
+    /// There are this number of elements, more than 3
+    TooMany(usize),
+}
+
+impl fmt::Display for OneToThreeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no element"),
+            Self::TooMany(len) => write!(f, "{} elements, expected at most 3", len),
+        }
+    }
+}
+
+impl std::error::Error for OneToThreeError {}
 
 /// An ordered set of 1, 2 or 3 elements, allowing pattern matching.
 ///
 /// Implements Copy, Clone, PartialEq, Eq, Debug, etc. if the element type does.
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub enum OneToThree<T> {
     One(T),
     Two(T, T),
     Three(T, T, T),
 }
 
+/// Const equivalents of the `From` tuple conversions.
+///
+/// They need `T: Copy` because the compiler can't prove in a const fn
+/// that moving out of a tuple doesn't run a destructor.
+impl<T: Copy> OneToThree<T> {
+    pub const fn from_tuple2(t: (T, T)) -> Self {
+        Self::Two(t.0, t.1)
+    }
+    pub const fn from_tuple3(t: (T, T, T)) -> Self {
+        Self::Three(t.0, t.1, t.2)
+    }
+}
+
 #[allow(clippy::len_without_is_empty)]
 impl<T> OneToThree<T> {
-    pub fn one(a: T) -> Self {
+    pub const fn one(a: T) -> Self {
         Self::One(a)
     }
-    pub fn two(a: T, b: T) -> Self {
+    pub const fn two(a: T, b: T) -> Self {
         Self::Two(a, b)
     }
-    pub fn three(a: T, b: T, c: T) -> Self {
+    pub const fn three(a: T, b: T, c: T) -> Self {
         Self::Three(a, b, c)
     }
-    pub fn len(&self) -> usize {
+    pub const fn len(&self) -> usize {
         match self {
             Self::One(_) => 1,
             Self::Two(_, _) => 2,
@@ -30,13 +73,45 @@ impl<T> OneToThree<T> {
     pub fn iter(&self) -> OneToThreeIter<'_, T> {
         OneToThreeIter::new(self)
     }
-    pub fn first(&self) -> &T {
+    /// an iterator over mutable references to the elements, in order
+    pub fn iter_mut(&mut self) -> OneToThreeIterMut<'_, T> {
+        let (items, len) = match self {
+            Self::One(a) => ([Some(a), None, None], 1),
+            Self::Two(a, b) => ([Some(a), Some(b), None], 2),
+            Self::Three(a, b, c) => ([Some(a), Some(b), Some(c)], 3),
+        };
+        OneToThreeIterMut {
+            items,
+            front: 0,
+            back: len,
+        }
+    }
+    /// Call `f` on every element, in order
+    pub fn for_each_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.iter_mut().for_each(f);
+    }
+    /// an iterator keeping the knowledge that there's at least one element
+    pub fn iter_ne(&self) -> NonEmptyIter<OneToThreeIter<'_, T>> {
+        NonEmptyIter::new(self.iter())
+    }
+    pub const fn first(&self) -> &T {
         match self {
             Self::One(f) => f,
             Self::Two(f, _) => f,
             Self::Three(f, _, _) => f,
         }
     }
+    /// the last element, which is also the first one when there's only one
+    pub const fn last(&self) -> &T {
+        match self {
+            Self::One(l) => l,
+            Self::Two(_, l) => l,
+            Self::Three(_, _, l) => l,
+        }
+    }
     pub fn first_mut(&mut self) -> &mut T {
         match self {
             Self::One(ref mut f) => f,
@@ -44,7 +119,32 @@ impl<T> OneToThree<T> {
             Self::Three(ref mut f, _, _) => f,
         }
     }
-    pub fn get(&self, i: usize) -> Option<&T> {
+    /// the last element, which is also the first one when there's only one
+    pub fn last_mut(&mut self) -> &mut T {
+        match self {
+            Self::One(ref mut l) => l,
+            Self::Two(_, ref mut l) => l,
+            Self::Three(_, _, ref mut l) => l,
+        }
+    }
+    /// Take the first element, dropping the other ones
+    pub fn into_first(self) -> T {
+        match self {
+            Self::One(f) => f,
+            Self::Two(f, _) => f,
+            Self::Three(f, _, _) => f,
+        }
+    }
+    /// Take the last element, dropping the other ones (when there's
+    /// only one element, it's also the first one)
+    pub fn into_last(self) -> T {
+        match self {
+            Self::One(l) => l,
+            Self::Two(_, l) => l,
+            Self::Three(_, _, l) => l,
+        }
+    }
+    pub const fn get(&self, i: usize) -> Option<&T> {
         match (i, self) {
             (0, _) => Some(self.first()),
             (1, Self::Two(_, b)) => Some(b),
@@ -78,6 +178,10 @@ impl<T> OneToThree<T> {
             Self::Three(a, b, c) => vec![a, b, c],
         }
     }
+    /// Return the elements sorted in ascending order.
+    ///
+    /// If some elements can't be compared (e.g. a NaN float), the order is
+    /// unspecified: consider using [`crate::FiniteF64`] in such a case.
     pub fn sorted(self) -> Self
     where
         T: PartialOrd,
@@ -110,6 +214,72 @@ impl<T> OneToThree<T> {
             }
         }
     }
+    /// Return the elements sorted with the `cmp` comparator.
+    ///
+    /// The sort is stable: equal elements keep their order.
+    pub fn sorted_by<F>(mut self, cmp: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.sort_by(cmp);
+        self
+    }
+    /// Return the elements sorted by the key computed by `f`.
+    ///
+    /// The sort is stable: elements with equal keys keep their order.
+    pub fn sorted_by_key<K, F>(mut self, f: F) -> Self
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by_key(f);
+        self
+    }
+    /// Sort the elements in place
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+    /// Sort the elements in place with the `cmp` comparator, by swaps.
+    ///
+    /// The sort is stable: equal elements keep their order.
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut less = |a: &T, b: &T| cmp(a, b) == Ordering::Less;
+        match self {
+            Self::One(_) => {}
+            Self::Two(a, b) => {
+                if less(b, a) {
+                    mem::swap(a, b);
+                }
+            }
+            Self::Three(a, b, c) => {
+                if less(b, a) {
+                    mem::swap(a, b);
+                }
+                if less(c, b) {
+                    mem::swap(b, c);
+                    if less(b, a) {
+                        mem::swap(a, b);
+                    }
+                }
+            }
+        }
+    }
+    /// Sort the elements in place by the key computed by `f`.
+    ///
+    /// The sort is stable: elements with equal keys keep their order.
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
     pub fn map<B, F>(self, f: F) -> OneToThree<B>
     where
         F: Fn(T) -> B,
@@ -120,6 +290,274 @@ impl<T> OneToThree<T> {
             Self::Three(a, b, c) => OneToThree::Three(f(a), f(b), f(c)),
         }
     }
+    /// Add an element at the end, or give back both the collection and
+    /// the value if there are already 3 elements.
+    ///
+    /// There's no in place `push`, as changing the variant behind a
+    /// `&mut` would need the elements to be moved out without a
+    /// placeholder.
+    pub fn with_pushed(self, value: T) -> Result<Self, (Self, T)> {
+        match self {
+            Self::One(a) => Ok(Self::Two(a, value)),
+            Self::Two(a, b) => Ok(Self::Three(a, b, value)),
+            three => Err((three, value)),
+        }
+    }
+    /// Remove the last element, returning the remaining ones, if any,
+    /// and the removed one
+    pub fn pop(self) -> (Option<Self>, T) {
+        match self {
+            Self::One(a) => (None, a),
+            Self::Two(a, b) => (Some(Self::One(a)), b),
+            Self::Three(a, b, c) => (Some(Self::Two(a, b)), c),
+        }
+    }
+    /// Remove the first element, returning it and the remaining ones,
+    /// if any
+    pub fn shift(self) -> (T, Option<Self>) {
+        match self {
+            Self::One(a) => (a, None),
+            Self::Two(a, b) => (a, Some(Self::One(b))),
+            Self::Three(a, b, c) => (a, Some(Self::Two(b, c))),
+        }
+    }
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self {
+            Self::One(a) => a == x,
+            Self::Two(a, b) => a == x || b == x,
+            Self::Three(a, b, c) => a == x || b == x || c == x,
+        }
+    }
+    /// Return the index of the first element matching `pred`
+    pub fn position<F>(&self, mut pred: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        match self {
+            Self::One(a) if pred(a) => Some(0),
+            Self::Two(a, b) => {
+                if pred(a) {
+                    Some(0)
+                } else if pred(b) {
+                    Some(1)
+                } else {
+                    None
+                }
+            }
+            Self::Three(a, b, c) => {
+                if pred(a) {
+                    Some(0)
+                } else if pred(b) {
+                    Some(1)
+                } else if pred(c) {
+                    Some(2)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+    /// Return the first element matching `pred`
+    pub fn find<F>(&self, pred: F) -> Option<&T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.position(pred).and_then(|i| self.get(i))
+    }
+    /// Return the first non-`None` result of `f` applied to the elements
+    pub fn find_map<B, F>(&self, mut f: F) -> Option<B>
+    where
+        F: FnMut(&T) -> Option<B>,
+    {
+        match self {
+            Self::One(a) => f(a),
+            Self::Two(a, b) => f(a).or_else(|| f(b)),
+            Self::Three(a, b, c) => f(a).or_else(|| f(b)).or_else(|| f(c)),
+        }
+    }
+    /// Tell whether an element matches `pred`, stopping at the first one
+    pub fn any<F>(&self, mut pred: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        match self {
+            Self::One(a) => pred(a),
+            Self::Two(a, b) => pred(a) || pred(b),
+            Self::Three(a, b, c) => pred(a) || pred(b) || pred(c),
+        }
+    }
+    /// Tell whether all elements match `pred`, stopping at the first
+    /// one which doesn't
+    pub fn all<F>(&self, mut pred: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        match self {
+            Self::One(a) => pred(a),
+            Self::Two(a, b) => pred(a) && pred(b),
+            Self::Three(a, b, c) => pred(a) && pred(b) && pred(c),
+        }
+    }
+    /// Return the number of elements matching `pred`
+    pub fn count_matching<F>(&self, mut pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        match self {
+            Self::One(a) => usize::from(pred(a)),
+            Self::Two(a, b) => usize::from(pred(a)) + usize::from(pred(b)),
+            Self::Three(a, b, c) => {
+                usize::from(pred(a)) + usize::from(pred(b)) + usize::from(pred(c))
+            }
+        }
+    }
+    /// Combine the elements from left to right.
+    ///
+    /// As there's always a first element, there's no need for an
+    /// initial value. To reduce borrowed elements, use `as_ref` first.
+    pub fn reduce<F>(self, mut f: F) -> T
+    where
+        F: FnMut(T, T) -> T,
+    {
+        match self {
+            Self::One(a) => a,
+            Self::Two(a, b) => f(a, b),
+            Self::Three(a, b, c) => {
+                let ab = f(a, b);
+                f(ab, c)
+            }
+        }
+    }
+    /// Fold the elements from left to right into an accumulator
+    pub fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        match self {
+            Self::One(a) => f(init, a),
+            Self::Two(a, b) => {
+                let acc = f(init, a);
+                f(acc, b)
+            }
+            Self::Three(a, b, c) => {
+                let acc = f(init, a);
+                let acc = f(acc, b);
+                f(acc, c)
+            }
+        }
+    }
+    /// Fold references to the elements from left to right into an
+    /// accumulator
+    pub fn fold_ref<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        self.as_ref().fold(init, f)
+    }
+    /// Return a displayable value writing the elements separated by
+    /// `sep`, with no allocation
+    pub fn display_join<'s>(&'s self, sep: &'s str) -> OneToThreeDisplay<'s, T>
+    where
+        T: fmt::Display,
+    {
+        self.display_join_with_last(sep, sep)
+    }
+    /// Return a displayable value writing the elements separated by
+    /// `sep`, except the last two ones which are separated by `last_sep`,
+    /// as in "a, b and c"
+    pub fn display_join_with_last<'s>(
+        &'s self,
+        sep: &'s str,
+        last_sep: &'s str,
+    ) -> OneToThreeDisplay<'s, T>
+    where
+        T: fmt::Display,
+    {
+        OneToThreeDisplay {
+            few: self,
+            sep,
+            last_sep,
+        }
+    }
+    /// Return the elements in an array, completed with clones of `fill`
+    pub fn to_padded_array(self, fill: T) -> [T; 3]
+    where
+        T: Clone,
+    {
+        match self {
+            Self::One(a) => [a, fill.clone(), fill],
+            Self::Two(a, b) => [a, b, fill],
+            Self::Three(a, b, c) => [a, b, c],
+        }
+    }
+    /// Insert an element at `idx`, or give back both the collection and
+    /// the value if there are already 3 elements or if `idx > len`
+    pub fn try_insert(self, idx: usize, value: T) -> Result<Self, (Self, T)> {
+        match (idx, self) {
+            (0, Self::One(a)) => Ok(Self::Two(value, a)),
+            (1, Self::One(a)) => Ok(Self::Two(a, value)),
+            (0, Self::Two(a, b)) => Ok(Self::Three(value, a, b)),
+            (1, Self::Two(a, b)) => Ok(Self::Three(a, value, b)),
+            (2, Self::Two(a, b)) => Ok(Self::Three(a, b, value)),
+            (_, few) => Err((few, value)),
+        }
+    }
+    /// Remove the element at `idx`, returning it and the remaining ones,
+    /// if any, or give back the collection if `idx >= len`
+    pub fn try_remove(self, idx: usize) -> Result<(T, Option<Self>), Self> {
+        match (idx, self) {
+            (0, Self::One(a)) => Ok((a, None)),
+            (0, Self::Two(a, b)) => Ok((a, Some(Self::One(b)))),
+            (1, Self::Two(a, b)) => Ok((b, Some(Self::One(a)))),
+            (0, Self::Three(a, b, c)) => Ok((a, Some(Self::Two(b, c)))),
+            (1, Self::Three(a, b, c)) => Ok((b, Some(Self::Two(a, c)))),
+            (2, Self::Three(a, b, c)) => Ok((c, Some(Self::Two(a, b)))),
+            (_, few) => Err(few),
+        }
+    }
+    /// Borrow the elements, like `Option::as_ref`, so that they can be
+    /// mapped without being moved or cloned:
+    ///
+    /// ```
+    /// use strict::OneToThree;
+    /// let names = OneToThree::two("Ann".to_string(), "Leopold".to_string());
+    /// let lens = names.as_ref().map(|name| name.len());
+    /// assert_eq!(lens, OneToThree::two(3, 7));
+    /// assert_eq!(names.first(), "Ann");
+    /// ```
+    pub fn as_ref(&self) -> OneToThree<&T> {
+        match self {
+            Self::One(a) => OneToThree::One(a),
+            Self::Two(a, b) => OneToThree::Two(a, b),
+            Self::Three(a, b, c) => OneToThree::Three(a, b, c),
+        }
+    }
+    /// Mutably borrow the elements, like `Option::as_mut`
+    pub fn as_mut(&mut self) -> OneToThree<&mut T> {
+        match self {
+            Self::One(a) => OneToThree::One(a),
+            Self::Two(a, b) => OneToThree::Two(a, b),
+            Self::Three(a, b, c) => OneToThree::Three(a, b, c),
+        }
+    }
+    /// Take the last 1 to 3 elements of the vec, in order, dropping
+    /// the other ones.
+    ///
+    /// Return `None` if the vec is empty.
+    pub fn from_last_of(mut v: Vec<T>) -> Option<Self> {
+        let c = v.pop()?;
+        Some(match v.pop() {
+            Some(b) => match v.pop() {
+                Some(a) => Self::Three(a, b, c),
+                None => Self::Two(b, c),
+            },
+            None => Self::One(c),
+        })
+    }
     pub fn try_map<B, E, F>(self, f: F) -> Result<OneToThree<B>, E>
     where
         F: Fn(T) -> Result<B, E>,
@@ -132,9 +570,44 @@ impl<T> OneToThree<T> {
     }
 }
 
-impl<T: Clone + Copy> Clone for OneToThree<T> {
+impl<T: Clone> OneToThree<&T> {
+    /// Clone the referenced elements
+    pub fn cloned(self) -> OneToThree<T> {
+        self.map(T::clone)
+    }
+}
+
+impl<T: Copy> OneToThree<&T> {
+    /// Copy the referenced elements
+    pub fn copied(self) -> OneToThree<T> {
+        self.map(|&v| v)
+    }
+}
+
+impl<T: Clone> Clone for OneToThree<T> {
     fn clone(&self) -> Self {
-        *self
+        match self {
+            Self::One(a) => Self::One(a.clone()),
+            Self::Two(a, b) => Self::Two(a.clone(), b.clone()),
+            Self::Three(a, b, c) => Self::Three(a.clone(), b.clone(), c.clone()),
+        }
+    }
+    /// Reuse the existing elements (and their allocations) when the
+    /// variants match
+    fn clone_from(&mut self, source: &Self) {
+        match (self, source) {
+            (Self::One(a), Self::One(sa)) => a.clone_from(sa),
+            (Self::Two(a, b), Self::Two(sa, sb)) => {
+                a.clone_from(sa);
+                b.clone_from(sb);
+            }
+            (Self::Three(a, b, c), Self::Three(sa, sb, sc)) => {
+                a.clone_from(sa);
+                b.clone_from(sb);
+                c.clone_from(sc);
+            }
+            (dst, source) => *dst = source.clone(),
+        }
     }
 }
 
@@ -160,6 +633,45 @@ impl<T: fmt::Debug> fmt::Debug for OneToThree<T> {
     }
 }
 
+/// Write the elements separated by `", "`.
+///
+/// Use [`OneToThree::display_join`] for another separator.
+impl<T: fmt::Display> fmt::Display for OneToThree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.display_join(", ").fmt(f)
+    }
+}
+
+/// The elements of a [`OneToThree`], displayed with separators.
+///
+/// Formatting flags (width, precision, etc.) apply to every element.
+#[derive(Debug, Clone, Copy)]
+pub struct OneToThreeDisplay<'s, T> {
+    few: &'s OneToThree<T>,
+    sep: &'s str,
+    last_sep: &'s str,
+}
+
+impl<T: fmt::Display> fmt::Display for OneToThreeDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.few {
+            OneToThree::One(a) => a.fmt(f),
+            OneToThree::Two(a, b) => {
+                a.fmt(f)?;
+                f.write_str(self.last_sep)?;
+                b.fmt(f)
+            }
+            OneToThree::Three(a, b, c) => {
+                a.fmt(f)?;
+                f.write_str(self.sep)?;
+                b.fmt(f)?;
+                f.write_str(self.last_sep)?;
+                c.fmt(f)
+            }
+        }
+    }
+}
+
 impl<T: hash::Hash> hash::Hash for OneToThree<T> {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         for e in self.iter() {
@@ -168,21 +680,57 @@ impl<T: hash::Hash> hash::Hash for OneToThree<T> {
     }
 }
 
-pub struct OneToThreeIter<'i, T> {
-    idx: usize,
-    few: &'i OneToThree<T>,
+pub type OneToThreeIter<'i, T> = FewIter<'i, OneToThree<T>>;
+
+impl<T> Few for OneToThree<T> {
+    type Item = T;
+    fn get(&self, i: usize) -> Option<&T> {
+        self.get(i)
+    }
+}
+
+/// An iterator over mutable references to the elements of a [`OneToThree`]
+pub struct OneToThreeIterMut<'i, T> {
+    items: [Option<&'i mut T>; 3],
+    front: usize,
+    back: usize,
 }
-impl<'i, T> OneToThreeIter<'i, T> {
-    pub fn new(few: &'i OneToThree<T>) -> Self {
-        Self { idx: 0, few }
+
+impl<'i, T> Iterator for OneToThreeIterMut<'i, T> {
+    type Item = &'i mut T;
+    fn next(&mut self) -> Option<&'i mut T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        self.items[self.front - 1].take()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
     }
 }
-impl<'i, T> Iterator for OneToThreeIter<'i, T> {
-    type Item = &'i T;
-    fn next(&mut self) -> Option<Self::Item> {
-        let i = self.idx;
-        self.idx += 1;
-        self.few.get(i)
+
+impl<T> DoubleEndedIterator for OneToThreeIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.items[self.back].take()
+    }
+}
+
+impl<T> ExactSizeIterator for OneToThreeIterMut<'_, T> {}
+
+impl<T> FusedIterator for OneToThreeIterMut<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a mut OneToThree<T> {
+    type Item = &'a mut T;
+    type IntoIter = OneToThreeIterMut<'a, T>;
+    #[inline]
+    fn into_iter(self) -> OneToThreeIterMut<'a, T> {
+        self.iter_mut()
     }
 }
 
@@ -195,18 +743,24 @@ impl<'a, T> IntoIterator for &'a OneToThree<T> {
     }
 }
 
+/// Fails if the vec is empty or has more than 3 elements
 impl<T> TryFrom<Vec<T>> for OneToThree<T> {
-    type Error = &'static str;
-    fn try_from(mut v: Vec<T>) -> Result<Self, Self::Error> {
-        let c = v.pop().ok_or("Empty vec")?;
-        if let Some(b) = v.pop() {
-            if let Some(a) = v.pop() {
-                Ok(Self::Three(a, b, c))
-            } else {
-                Ok(Self::Two(b, c))
-            }
-        } else {
-            Ok(Self::One(c))
+    type Error = OneToThreeError;
+    fn try_from(v: Vec<T>) -> Result<Self, Self::Error> {
+        if v.len() > 3 {
+            return Err(OneToThreeError::TooMany(v.len()));
+        }
+        Self::from_last_of(v).ok_or(OneToThreeError::Empty)
+    }
+}
+
+/// The elements are moved through a stack array, with no intermediate vec
+impl<T> From<OneToThree<T>> for Arc<[T]> {
+    fn from(few: OneToThree<T>) -> Self {
+        match few {
+            OneToThree::One(a) => [a].into_iter().collect(),
+            OneToThree::Two(a, b) => [a, b].into_iter().collect(),
+            OneToThree::Three(a, b, c) => [a, b, c].into_iter().collect(),
         }
     }
 }
@@ -227,6 +781,82 @@ impl<T> From<(T, T, T)> for OneToThree<T> {
     }
 }
 
+impl<T> From<[T; 1]> for OneToThree<T> {
+    fn from([a]: [T; 1]) -> Self {
+        Self::One(a)
+    }
+}
+impl<T> From<[T; 2]> for OneToThree<T> {
+    fn from([a, b]: [T; 2]) -> Self {
+        Self::Two(a, b)
+    }
+}
+impl<T> From<[T; 3]> for OneToThree<T> {
+    fn from([a, b, c]: [T; 3]) -> Self {
+        Self::Three(a, b, c)
+    }
+}
+
+/// Gives back the value when there's not exactly one element
+impl<T> TryFrom<OneToThree<T>> for [T; 1] {
+    type Error = OneToThree<T>;
+    fn try_from(few: OneToThree<T>) -> Result<Self, Self::Error> {
+        match few {
+            OneToThree::One(a) => Ok([a]),
+            few => Err(few),
+        }
+    }
+}
+/// Gives back the value when there are not exactly two elements
+impl<T> TryFrom<OneToThree<T>> for [T; 2] {
+    type Error = OneToThree<T>;
+    fn try_from(few: OneToThree<T>) -> Result<Self, Self::Error> {
+        match few {
+            OneToThree::Two(a, b) => Ok([a, b]),
+            few => Err(few),
+        }
+    }
+}
+/// Gives back the value when there are not exactly three elements
+impl<T> TryFrom<OneToThree<T>> for [T; 3] {
+    type Error = OneToThree<T>;
+    fn try_from(few: OneToThree<T>) -> Result<Self, Self::Error> {
+        match few {
+            OneToThree::Three(a, b, c) => Ok([a, b, c]),
+            few => Err(few),
+        }
+    }
+}
+
+impl<T> crate::non_empty::Sealed for OneToThree<T> {}
+
+impl<T> NonEmpty for OneToThree<T> {
+    type Item = T;
+    type Iter<'a>
+        = OneToThreeIter<'a, T>
+    where
+        T: 'a;
+    #[inline]
+    fn len(&self) -> NonZeroUsize {
+        match NonZeroUsize::new(self.len()) {
+            Some(len) => len,
+            None => unreachable!(),
+        }
+    }
+    #[inline]
+    fn first(&self) -> &T {
+        self.first()
+    }
+    #[inline]
+    fn last(&self) -> &T {
+        self.last()
+    }
+    #[inline]
+    fn iter(&self) -> OneToThreeIter<'_, T> {
+        self.iter()
+    }
+}
+
 #[test]
 fn test_sort() {
     assert_eq!(OneToThree::one(1).sorted(), OneToThree::one(1));
@@ -264,6 +894,67 @@ fn test_sort() {
     );
 }
 
+#[test]
+fn test_sort_by() {
+    // all orders of 3 elements, with the letter telling the input position
+    let keys = [
+        [(1, 'a'), (2, 'b'), (3, 'c')],
+        [(1, 'a'), (3, 'b'), (2, 'c')],
+        [(2, 'a'), (1, 'b'), (3, 'c')],
+        [(2, 'a'), (3, 'b'), (1, 'c')],
+        [(3, 'a'), (1, 'b'), (2, 'c')],
+        [(3, 'a'), (2, 'b'), (1, 'c')],
+    ];
+    for [a, b, c] in keys {
+        let few = OneToThree::three(a, b, c).sorted_by_key(|&(k, _)| k);
+        assert_eq!(few.map(|(k, _)| k), OneToThree::three(1, 2, 3));
+        let few = OneToThree::three(a, b, c).sorted_by(|x, y| y.cmp(x));
+        assert_eq!(few.map(|(k, _)| k), OneToThree::three(3, 2, 1));
+    }
+    // stability, with equal keys
+    let stable = |a, b, c| {
+        OneToThree::three(a, b, c)
+            .sorted_by_key(|&(k, _)| k)
+            .map(|(_, p)| p)
+    };
+    assert_eq!(
+        stable((1, 'a'), (1, 'b'), (1, 'c')),
+        OneToThree::three('a', 'b', 'c')
+    );
+    assert_eq!(
+        stable((2, 'a'), (1, 'b'), (2, 'c')),
+        OneToThree::three('b', 'a', 'c')
+    );
+    assert_eq!(
+        stable((2, 'a'), (2, 'b'), (1, 'c')),
+        OneToThree::three('c', 'a', 'b')
+    );
+    assert_eq!(
+        stable((1, 'a'), (2, 'b'), (1, 'c')),
+        OneToThree::three('a', 'c', 'b')
+    );
+    assert_eq!(
+        stable((2, 'a'), (1, 'b'), (1, 'c')),
+        OneToThree::three('b', 'c', 'a')
+    );
+    assert_eq!(
+        OneToThree::two((1, 'a'), (1, 'b')).sorted_by_key(|&(k, _)| k),
+        OneToThree::two((1, 'a'), (1, 'b'))
+    );
+    // in place
+    let mut words = OneToThree::three("ccc", "a", "bb");
+    words.sort();
+    assert_eq!(words, OneToThree::three("a", "bb", "ccc"));
+    words.sort_by_key(|w| std::cmp::Reverse(w.len()));
+    assert_eq!(words, OneToThree::three("ccc", "bb", "a"));
+    let mut two = OneToThree::two(4, 3);
+    two.sort_by(|a, b| a.cmp(b));
+    assert_eq!(two, OneToThree::two(3, 4));
+    let mut one = OneToThree::one(1);
+    one.sort();
+    assert_eq!(one, OneToThree::one(1));
+}
+
 #[test]
 fn test_map() {
     assert_eq!(
@@ -284,3 +975,300 @@ fn test_try_map() {
         .try_map::<usize, _, _>(|x| x.parse())
         .is_err());
 }
+
+#[test]
+fn test_try_from_vec() {
+    assert_eq!(
+        OneToThree::<u8>::try_from(vec![]),
+        Err(OneToThreeError::Empty)
+    );
+    assert_eq!(OneToThree::try_from(vec![1]), Ok(OneToThree::one(1)));
+    assert_eq!(OneToThree::try_from(vec![1, 2]), Ok(OneToThree::two(1, 2)));
+    assert_eq!(
+        OneToThree::try_from(vec![1, 2, 3]),
+        Ok(OneToThree::three(1, 2, 3))
+    );
+    assert_eq!(
+        OneToThree::<u8>::try_from(vec![1, 2, 3, 4, 5]),
+        Err(OneToThreeError::TooMany(5))
+    );
+    assert_eq!(OneToThree::<u8>::from_last_of(vec![]), None);
+    assert_eq!(
+        OneToThree::from_last_of(vec![1, 2]),
+        Some(OneToThree::two(1, 2))
+    );
+    assert_eq!(
+        OneToThree::from_last_of(vec![1, 2, 3, 4, 5]),
+        Some(OneToThree::three(3, 4, 5))
+    );
+}
+
+#[test]
+fn test_clone() {
+    fn assert_copy<T: Copy>() {}
+    assert_copy::<OneToThree<u32>>();
+    let names = OneToThree::two("Ann".to_string(), "Bob".to_string());
+    assert_eq!(names.clone(), names);
+    let bytes = OneToThree::three(vec![1u8], vec![], vec![2, 3]);
+    assert_eq!(bytes.clone(), bytes);
+    let mut dst = OneToThree::three(Vec::with_capacity(10), vec![0], vec![]);
+    let ptr = dst.first().as_ptr();
+    dst.clone_from(&bytes);
+    assert_eq!(dst, bytes);
+    assert_eq!(dst.first().as_ptr(), ptr);
+    dst.clone_from(&OneToThree::one(vec![4]));
+    assert_eq!(dst, OneToThree::one(vec![4]));
+}
+
+#[test]
+fn test_iter_mut() {
+    let mut three = OneToThree::three(1, 2, 3);
+    for v in three.iter_mut() {
+        *v *= 10;
+    }
+    assert_eq!(three, OneToThree::three(10, 20, 30));
+    for v in &mut three {
+        *v += 1;
+    }
+    assert_eq!(three, OneToThree::three(11, 21, 31));
+    let mut iter = three.iter_mut();
+    assert_eq!(iter.len(), 3);
+    *iter.next_back().unwrap() = 0;
+    assert_eq!(iter.len(), 2);
+    *iter.next().unwrap() = 5;
+    assert_eq!(iter.next_back(), Some(&mut 21));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(three, OneToThree::three(5, 21, 0));
+    let mut one = OneToThree::one("a".to_string());
+    one.for_each_mut(|s| s.push('b'));
+    assert_eq!(one, OneToThree::one("ab".to_string()));
+    let mut two = OneToThree::two(1, 2);
+    assert_eq!(two.iter_mut().rev().map(|v| *v).collect::<Vec<_>>(), [2, 1]);
+}
+
+#[test]
+fn test_first_last() {
+    let mut one = OneToThree::one(1);
+    assert_eq!(one.first(), one.last());
+    *one.last_mut() = 2;
+    assert_eq!(*one.first(), 2);
+    assert_eq!(one.into_first(), 2);
+    assert_eq!(one.into_last(), 2);
+    let mut two = OneToThree::two("a".to_string(), "b".to_string());
+    two.last_mut().push('!');
+    assert_eq!(two.last(), "b!");
+    assert_eq!(two.clone().into_first(), "a");
+    assert_eq!(two.into_last(), "b!");
+    let mut three = OneToThree::three(1, 2, 3);
+    *three.last_mut() += 1;
+    assert_eq!(three.last(), &4);
+    assert_eq!(three.into_first(), 1);
+    assert_eq!(three.into_last(), 4);
+}
+
+#[test]
+fn test_as_ref_as_mut() {
+    let one = OneToThree::one("a".to_string());
+    assert_eq!(one.as_ref(), OneToThree::one(&"a".to_string()));
+    assert_eq!(one.as_ref().cloned(), one);
+    let mut two = OneToThree::two(1, 2);
+    two.as_mut().map(|v| *v *= 3);
+    assert_eq!(two, OneToThree::two(3, 6));
+    assert_eq!(two.as_ref().copied(), two);
+    let mut three = OneToThree::three(vec![1], vec![2, 3], vec![]);
+    assert_eq!(three.as_ref().map(|v| v.len()), OneToThree::three(1, 2, 0));
+    if let OneToThree::Three(_, _, c) = three.as_mut() {
+        c.push(4);
+    }
+    assert_eq!(three.last(), &[4]);
+}
+
+#[test]
+fn test_with_pushed() {
+    let few = OneToThree::one("a".to_string());
+    let few = few.with_pushed("b".to_string()).unwrap();
+    assert_eq!(few.len(), 2);
+    let few = few.with_pushed("c".to_string()).unwrap();
+    let (few, rejected) = few.with_pushed("d".to_string()).unwrap_err();
+    assert_eq!(rejected, "d");
+    assert_eq!(few, OneToThree::three("a", "b", "c").map(String::from));
+}
+
+#[test]
+fn test_pop_shift() {
+    assert_eq!(OneToThree::one(1).pop(), (None, 1));
+    assert_eq!(OneToThree::two(1, 2).pop(), (Some(OneToThree::one(1)), 2));
+    assert_eq!(
+        OneToThree::three(1, 2, 3).pop(),
+        (Some(OneToThree::two(1, 2)), 3)
+    );
+    assert_eq!(OneToThree::one(1).shift(), (1, None));
+    assert_eq!(OneToThree::two(1, 2).shift(), (1, Some(OneToThree::one(2))));
+    assert_eq!(
+        OneToThree::three(1, 2, 3).shift(),
+        (1, Some(OneToThree::two(2, 3)))
+    );
+}
+
+#[test]
+fn test_insert_remove() {
+    let few = OneToThree::one('b');
+    let few = few.try_insert(0, 'a').unwrap();
+    assert_eq!(few, OneToThree::two('a', 'b'));
+    assert_eq!(few.try_insert(3, 'x'), Err((few, 'x')));
+    let few = few.try_insert(2, 'd').unwrap();
+    assert_eq!(few, OneToThree::three('a', 'b', 'd'));
+    assert_eq!(few.try_insert(1, 'x'), Err((few, 'x')));
+    assert_eq!(
+        OneToThree::one(1).try_insert(1, 2),
+        Ok(OneToThree::two(1, 2))
+    );
+    assert_eq!(
+        OneToThree::two(1, 3).try_insert(1, 2),
+        Ok(OneToThree::three(1, 2, 3))
+    );
+    assert_eq!(
+        few.try_remove(0),
+        Ok(('a', Some(OneToThree::two('b', 'd'))))
+    );
+    assert_eq!(
+        few.try_remove(1),
+        Ok(('b', Some(OneToThree::two('a', 'd'))))
+    );
+    assert_eq!(
+        few.try_remove(2),
+        Ok(('d', Some(OneToThree::two('a', 'b'))))
+    );
+    assert_eq!(few.try_remove(3), Err(few));
+    assert_eq!(OneToThree::one(1).try_remove(0), Ok((1, None)));
+    assert_eq!(OneToThree::one(1).try_remove(1), Err(OneToThree::one(1)));
+    assert_eq!(
+        OneToThree::two(1, 2).try_remove(1),
+        Ok((2, Some(OneToThree::one(1))))
+    );
+}
+
+#[test]
+fn test_search() {
+    let one = OneToThree::one(4u32);
+    assert!(one.contains(&4));
+    assert!(!one.contains(&5));
+    assert_eq!(one.position(|&v| v == 4), Some(0));
+    assert_eq!(one.position(|&v| v == 5), None);
+    assert_eq!(one.find(|&v| v > 3), Some(&4));
+    assert_eq!(one.find_map(|&v| v.checked_sub(5)), None);
+    let three = OneToThree::three("a", "bb", "cc");
+    assert!(three.contains(&"cc"));
+    assert!(!three.contains(&"d"));
+    assert_eq!(three.position(|s| s.len() == 2), Some(1));
+    assert_eq!(three.position(|s| s.is_empty()), None);
+    let found = three.find(|s| s.len() == 2).unwrap();
+    assert!(std::ptr::eq(found, three.get(1).unwrap()));
+    assert_eq!(three.find_map(|s| s.strip_prefix('c')), Some("c"));
+    assert_eq!(three.find_map(|s| s.strip_prefix('d')), None);
+    let two = OneToThree::two(3, 3);
+    assert_eq!(two.position(|&v| v == 3), Some(0));
+}
+
+#[test]
+fn test_any_all() {
+    let keys = OneToThree::three("ctrl", "shift", "a");
+    let mut calls = 0;
+    assert!(keys.any(|&k| {
+        calls += 1;
+        k == "ctrl"
+    }));
+    assert_eq!(calls, 1);
+    calls = 0;
+    assert!(!keys.all(|k| {
+        calls += 1;
+        k.len() > 1 && k.len() < 5
+    }));
+    assert_eq!(calls, 2);
+    assert!(keys.all(|k| !k.is_empty()));
+    assert!(!keys.any(|k| k.is_empty()));
+    assert_eq!(keys.count_matching(|k| k.len() > 1), 2);
+    let one = OneToThree::one(3);
+    assert!(one.any(|&v| v == 3));
+    assert!(one.all(|&v| v == 3));
+    assert_eq!(one.count_matching(|&v| v == 4), 0);
+}
+
+#[test]
+fn test_fold_reduce() {
+    let concat = |a: String, b: String| a + &b;
+    assert_eq!(OneToThree::one("a".to_string()).reduce(concat), "a");
+    let few = OneToThree::three("a", "b", "c").map(String::from);
+    assert_eq!(few.clone().reduce(concat), "abc");
+    assert_eq!(few.fold_ref(String::from(">"), |acc, s| acc + s), ">abc");
+    assert_eq!(few.fold("-".to_string(), concat), "-abc");
+    let two = OneToThree::two(2, 10);
+    assert_eq!(two.reduce(|a, b| a - b), -8);
+    assert_eq!(two.fold(100, |acc, v| acc / v), 5);
+    assert_eq!(two.as_ref().reduce(std::cmp::max), &10);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(OneToThree::one("t").to_string(), "t");
+    assert_eq!(OneToThree::two("alt", "t").to_string(), "alt, t");
+    let keys = OneToThree::three("ctrl", "alt", "t");
+    assert_eq!(keys.to_string(), "ctrl, alt, t");
+    assert_eq!(keys.display_join("-").to_string(), "ctrl-alt-t");
+    assert_eq!(OneToThree::one(1).display_join("-").to_string(), "1");
+    let names = OneToThree::three("Ann", "Bob", "Cid");
+    assert_eq!(
+        names.display_join_with_last(", ", " and ").to_string(),
+        "Ann, Bob and Cid"
+    );
+    let two = OneToThree::two(1.0, 2.25);
+    assert_eq!(
+        two.display_join_with_last(", ", " or ").to_string(),
+        "1 or 2.25"
+    );
+    assert_eq!(format!("{:.1}", two), "1.0, 2.2");
+    assert_eq!(format!("{:>3}", OneToThree::one(7)), "  7");
+}
+
+#[test]
+fn test_arrays() {
+    assert_eq!(OneToThree::from([1]), OneToThree::one(1));
+    assert_eq!(OneToThree::from([1, 2]), OneToThree::two(1, 2));
+    assert_eq!(OneToThree::from([1, 2, 3]), OneToThree::three(1, 2, 3));
+    let few = OneToThree::from(["a", "b"].map(String::from));
+    let few = <[String; 3]>::try_from(few).unwrap_err();
+    let few = <[String; 1]>::try_from(few).unwrap_err();
+    assert_eq!(<[String; 2]>::try_from(few).unwrap(), ["a", "b"]);
+    assert_eq!(<[u8; 1]>::try_from(OneToThree::one(4)), Ok([4]));
+    assert_eq!(
+        <[u8; 3]>::try_from(OneToThree::three(4, 5, 6)),
+        Ok([4, 5, 6])
+    );
+    assert_eq!(OneToThree::one(1).to_padded_array(0), [1, 0, 0]);
+    assert_eq!(OneToThree::two(1, 2).to_padded_array(0), [1, 2, 0]);
+    assert_eq!(OneToThree::three(1, 2, 3).to_padded_array(0), [1, 2, 3]);
+}
+
+#[test]
+fn test_into_arc() {
+    let arc: Arc<[String]> = OneToThree::two("a".to_string(), "b".to_string()).into();
+    assert_eq!(&*arc, &["a".to_string(), "b".to_string()]);
+    assert_eq!(Arc::<[u8]>::from(OneToThree::one(3)).len(), 1);
+}
+
+#[test]
+fn test_const() {
+    static PAIR: OneToThree<u8> = OneToThree::from_tuple2((4, 5));
+    const TRIPLE: OneToThree<&str> = OneToThree::from_tuple3(("a", "b", "c"));
+    const LEN: usize = TRIPLE.len();
+    const SECOND: Option<&&str> = TRIPLE.get(1);
+    const ONE: OneToThree<u8> = OneToThree::one(4);
+    const FIRST: &u8 = ONE.first();
+    assert_eq!(PAIR, OneToThree::from((4, 5)));
+    assert_eq!(PAIR.len(), 2);
+    assert_eq!(LEN, 3);
+    assert_eq!(SECOND, Some(&"b"));
+    assert_eq!(*FIRST, 4);
+    assert_eq!(TRIPLE, OneToThree::from(("a", "b", "c")));
+}
@@ -0,0 +1,210 @@
+//! [proptest](https://docs.rs/proptest) strategies.
+//!
+//! Shrinking never breaks the invariants: a shrunk [`NonEmptyVec`] keeps
+//! at least one element, while a [`OneToThree`] may shrink down to `One`.
+//!
+//! The types also implement [`Arbitrary`], so that `any::<NonEmptyVec<u8>>()`
+//! just works.
+
+use {
+    crate::{
+        AtMostVec, BoundedInt, FiniteF32, FiniteF64, NonEmptyString, NonEmptyVec, OneOrTwo,
+        OneToThree, UnitInterval,
+    },
+    proptest::{
+        arbitrary::{any, any_with, Arbitrary},
+        collection,
+        strategy::{BoxedStrategy, Strategy},
+    },
+};
+
+/// Default max length of generated non empty vecs
+const DEFAULT_MAX_LEN: usize = 16;
+
+/// Generate non empty vecs of up to `max_len` elements
+///
+/// Panics if `max_len` is 0.
+pub fn non_empty_vec<S: Strategy>(
+    element: S,
+    max_len: usize,
+) -> impl Strategy<Value = NonEmptyVec<S::Value>> {
+    assert!(max_len > 0, "max_len must be at least 1");
+    collection::vec(element, 1..=max_len).prop_map(|vec| NonEmptyVec { vec })
+}
+
+pub fn one_to_three<S: Strategy>(element: S) -> impl Strategy<Value = OneToThree<S::Value>> {
+    collection::vec(element, 1..=3).prop_map(|vec| {
+        let mut iter = vec.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(a), Some(b), Some(c)) => OneToThree::Three(a, b, c),
+            (Some(a), Some(b), None) => OneToThree::Two(a, b),
+            (Some(a), None, _) => OneToThree::One(a),
+            _ => unreachable!(),
+        }
+    })
+}
+
+pub fn one_or_two<S: Strategy>(element: S) -> impl Strategy<Value = OneOrTwo<S::Value>> {
+    collection::vec(element, 1..=2).prop_map(|vec| {
+        let mut iter = vec.into_iter();
+        match (iter.next(), iter.next()) {
+            (Some(a), Some(b)) => OneOrTwo::Two(a, b),
+            (Some(a), None) => OneOrTwo::One(a),
+            _ => unreachable!(),
+        }
+    })
+}
+
+pub fn at_most_vec<S: Strategy, const MAX: usize>(
+    element: S,
+) -> impl Strategy<Value = AtMostVec<S::Value, MAX>> {
+    collection::vec(element, 0..=MAX).prop_map(|vec| match AtMostVec::try_from(vec) {
+        Ok(vec) => vec,
+        Err(_) => unreachable!(),
+    })
+}
+
+pub fn bounded_int<const MIN: i64, const MAX: i64>() -> impl Strategy<Value = BoundedInt<MIN, MAX>>
+{
+    (MIN..=MAX).prop_map(|value| match BoundedInt::try_new(value) {
+        Ok(value) => value,
+        Err(_) => unreachable!(),
+    })
+}
+
+pub fn finite_f64() -> impl Strategy<Value = FiniteF64> {
+    use proptest::num::f64::*;
+    (POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO).prop_map(|v| match FiniteF64::try_new(v) {
+        Ok(v) => v,
+        Err(_) => unreachable!(),
+    })
+}
+
+pub fn finite_f32() -> impl Strategy<Value = FiniteF32> {
+    use proptest::num::f32::*;
+    (POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO).prop_map(|v| match FiniteF32::try_new(v) {
+        Ok(v) => v,
+        Err(_) => unreachable!(),
+    })
+}
+
+pub fn unit_interval() -> impl Strategy<Value = UnitInterval> {
+    (0.0..=1.0).prop_map(UnitInterval::new_clamped)
+}
+
+pub fn non_empty_string() -> impl Strategy<Value = NonEmptyString> {
+    (any::<char>(), any::<String>()).prop_map(|(first, rest)| {
+        let mut string = first.to_string();
+        string.push_str(&rest);
+        NonEmptyString { string }
+    })
+}
+
+impl<T: Arbitrary + 'static> Arbitrary for NonEmptyVec<T> {
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        non_empty_vec(any_with::<T>(args), DEFAULT_MAX_LEN).boxed()
+    }
+}
+
+impl<T: Arbitrary + 'static> Arbitrary for OneToThree<T> {
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        one_to_three(any_with::<T>(args)).boxed()
+    }
+}
+
+impl<T: Arbitrary + 'static> Arbitrary for OneOrTwo<T> {
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        one_or_two(any_with::<T>(args)).boxed()
+    }
+}
+
+impl<T: Arbitrary + 'static, const MAX: usize> Arbitrary for AtMostVec<T, MAX> {
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        at_most_vec(any_with::<T>(args)).boxed()
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> Arbitrary for BoundedInt<MIN, MAX> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        bounded_int().boxed()
+    }
+}
+
+macro_rules! impl_arbitrary {
+    ($name:ident, $strategy:ident) => {
+        impl Arbitrary for $name {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                $strategy().boxed()
+            }
+        }
+    };
+}
+
+impl_arbitrary!(FiniteF64, finite_f64);
+impl_arbitrary!(FiniteF32, finite_f32);
+impl_arbitrary!(UnitInterval, unit_interval);
+impl_arbitrary!(NonEmptyString, non_empty_string);
+
+#[cfg(test)]
+mod proptest_tests {
+
+    use {
+        super::*,
+        proptest::{
+            prelude::*,
+            test_runner::{TestError, TestRunner},
+        },
+    };
+
+    proptest! {
+        #[test]
+        fn test_invariants(
+            vec in non_empty_vec(any::<u8>(), 5),
+            few in any::<OneToThree<i32>>(),
+            at_most in any::<AtMostVec<u8, 2>>(),
+            month in any::<BoundedInt<1, 12>>(),
+            f in any::<FiniteF64>(),
+            u in any::<UnitInterval>(),
+            s in any::<NonEmptyString>(),
+        ) {
+            prop_assert!((1..=5).contains(&vec.len().get()));
+            prop_assert!((1..=3).contains(&few.len()));
+            prop_assert!(at_most.len() <= 2);
+            prop_assert!((1..=12).contains(&month.get()));
+            prop_assert!(f.get().is_finite());
+            prop_assert!((0.0..=1.0).contains(&u.get()));
+            prop_assert!(!s.as_str().is_empty());
+        }
+    }
+
+    /// Return the minimal value after shrinking a property which always fails
+    fn shrunk<S: Strategy>(strategy: S) -> S::Value {
+        let mut runner = TestRunner::deterministic();
+        match runner.run(&strategy, |_| Err(TestCaseError::fail("always"))) {
+            Err(TestError::Fail(_, value)) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_shrinking_keeps_invariants() {
+        let vec = shrunk(non_empty_vec(any::<u8>(), 10));
+        assert_eq!(vec.as_slice(), &[0]);
+        assert_eq!(shrunk(one_to_three(any::<u8>())), OneToThree::One(0));
+        assert_eq!(shrunk(one_or_two(any::<u8>())), OneOrTwo::One(0));
+        assert!(shrunk(any::<NonEmptyString>()).len().get() >= 1);
+        assert_eq!(shrunk(bounded_int::<3, 7>()).get(), 3);
+    }
+}
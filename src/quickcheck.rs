@@ -0,0 +1,207 @@
+//! Implementations of [`quickcheck::Arbitrary`].
+//!
+//! Shrinking respects the invariants: a [`NonEmptyVec`] never shrinks to an
+//! empty vec, while a `Three` shrinks to `Two` and `One`, then element-wise.
+
+use {
+    crate::{
+        AtMostVec, BoundedInt, FiniteF32, FiniteF64, NonEmptyString, NonEmptyVec, OneOrTwo,
+        OneToThree, UnitInterval,
+    },
+    quickcheck::{Arbitrary, Gen},
+    std::convert::TryFrom,
+};
+
+/// Shrink the vec, skipping the empty candidates
+fn shrink_non_empty<T: Arbitrary>(vec: Vec<T>) -> impl Iterator<Item = Vec<T>> {
+    vec.shrink().filter(|v| !v.is_empty())
+}
+
+/// Shrink the vec element-wise, keeping its length
+fn shrink_elements<T: Arbitrary>(vec: Vec<T>) -> impl Iterator<Item = Vec<T>> {
+    let len = vec.len();
+    vec.shrink().filter(move |v| v.len() == len)
+}
+
+impl<T: Arbitrary> Arbitrary for NonEmptyVec<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut vec = vec![T::arbitrary(g)];
+        vec.extend(Vec::<T>::arbitrary(g));
+        Self { vec }
+    }
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(shrink_non_empty(self.vec.clone()).map(|vec| Self { vec }))
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for OneToThree<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        match g.choose(&[1, 2, 3]) {
+            Some(1) => Self::One(T::arbitrary(g)),
+            Some(2) => Self::Two(T::arbitrary(g), T::arbitrary(g)),
+            _ => Self::Three(T::arbitrary(g), T::arbitrary(g), T::arbitrary(g)),
+        }
+    }
+    /// Propose the smaller variants first, then element-wise shrinks
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let smaller = match self {
+            Self::One(_) => vec![],
+            Self::Two(a, b) => vec![Self::One(a.clone()), Self::One(b.clone())],
+            Self::Three(a, b, c) => vec![
+                Self::One(a.clone()),
+                Self::One(b.clone()),
+                Self::One(c.clone()),
+                Self::Two(a.clone(), b.clone()),
+                Self::Two(a.clone(), c.clone()),
+                Self::Two(b.clone(), c.clone()),
+            ],
+        };
+        let elements =
+            shrink_elements(self.clone().to_vec()).map(|vec| match Self::try_from(vec) {
+                Ok(few) => few,
+                Err(_) => unreachable!(),
+            });
+        Box::new(smaller.into_iter().chain(elements))
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for OneOrTwo<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        match g.choose(&[1, 2]) {
+            Some(1) => Self::One(T::arbitrary(g)),
+            _ => Self::Two(T::arbitrary(g), T::arbitrary(g)),
+        }
+    }
+    /// Propose the `One` variants first, then element-wise shrinks
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let smaller = match self {
+            Self::One(_) => vec![],
+            Self::Two(a, b) => vec![Self::One(a.clone()), Self::One(b.clone())],
+        };
+        let elements =
+            shrink_elements(self.clone().to_vec()).map(|vec| match Self::try_from(vec) {
+                Ok(few) => few,
+                Err(_) => unreachable!(),
+            });
+        Box::new(smaller.into_iter().chain(elements))
+    }
+}
+
+impl<T: Arbitrary, const MAX: usize> Arbitrary for AtMostVec<T, MAX> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut vec = Vec::<T>::arbitrary(g);
+        vec.truncate(MAX);
+        match Self::try_from(vec) {
+            Ok(vec) => vec,
+            Err(_) => unreachable!(),
+        }
+    }
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.as_slice()
+                .to_vec()
+                .shrink()
+                .filter_map(|vec| Self::try_from(vec).ok()),
+        )
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> Arbitrary for BoundedInt<MIN, MAX> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let span = MAX as i128 - MIN as i128 + 1;
+        let value = MIN as i128 + (i64::arbitrary(g) as i128).rem_euclid(span);
+        Self::new_saturating(value as i64)
+    }
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.get().shrink().filter_map(|v| Self::try_new(v).ok()))
+    }
+}
+
+macro_rules! impl_finite_float {
+    ($name:ident, $float:ty) => {
+        /// Non finite floats are replaced with `ZERO`
+        impl Arbitrary for $name {
+            fn arbitrary(g: &mut Gen) -> Self {
+                Self::try_new(<$float>::arbitrary(g)).unwrap_or(Self::ZERO)
+            }
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(self.get().shrink().filter_map(|v| Self::try_new(v).ok()))
+            }
+        }
+    };
+}
+
+impl_finite_float!(FiniteF32, f32);
+impl_finite_float!(FiniteF64, f64);
+
+impl Arbitrary for UnitInterval {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self::new_clamped(u32::arbitrary(g) as f64 / u32::MAX as f64)
+    }
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.get().shrink().filter_map(|v| Self::try_new(v).ok()))
+    }
+}
+
+impl Arbitrary for NonEmptyString {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut string = char::arbitrary(g).to_string();
+        string.push_str(&String::arbitrary(g));
+        Self { string }
+    }
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.string
+                .shrink()
+                .filter(|s| !s.is_empty())
+                .map(|string| Self { string }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod quickcheck_tests {
+
+    use {super::*, quickcheck::quickcheck};
+
+    quickcheck! {
+        fn sorted_is_an_ordered_permutation(few: OneToThree<i32>) -> bool {
+            let mut expected = few.to_vec();
+            expected.sort_unstable();
+            few.sorted().to_vec() == expected
+        }
+
+        fn non_empty_vec_shrinks_stay_non_empty(vec: NonEmptyVec<u8>) -> bool {
+            vec.shrink().all(|v| !v.as_slice().is_empty())
+        }
+
+        fn bounded_int_stays_in_bounds(v: BoundedInt<-3, 1000>) -> bool {
+            (-3..=1000).contains(&v.get()) && v.shrink().all(|v| (-3..=1000).contains(&v.get()))
+        }
+
+        fn at_most_vec_stays_small(v: AtMostVec<u8, 4>) -> bool {
+            v.len() <= 4
+        }
+
+        fn floats_are_finite(f: FiniteF64, u: UnitInterval) -> bool {
+            f.get().is_finite() && (0.0..=1.0).contains(&u.get())
+        }
+    }
+
+    #[test]
+    fn test_shrink() {
+        let vec = NonEmptyVec::from(0u8);
+        assert_eq!(vec.shrink().count(), 0);
+        let vec = NonEmptyVec::from(5u8);
+        assert!(vec.shrink().all(|v| v.has_len(1)));
+        let shrunk: Vec<_> = OneToThree::three(0u8, 0, 0).shrink().collect();
+        assert!(shrunk.contains(&OneToThree::two(0, 0)));
+        assert_eq!(shrunk[0], OneToThree::one(0));
+        let shrunk: Vec<_> = OneOrTwo::Two(1u8, 2).shrink().collect();
+        assert_eq!(&shrunk[..2], &[OneOrTwo::One(1), OneOrTwo::One(2)]);
+        let shrunk: Vec<_> = OneToThree::one(4u8).shrink().collect();
+        assert!(!shrunk.is_empty());
+        assert!(shrunk.iter().all(|few| few.len() == 1));
+        assert!(NonEmptyString::from('a').shrink().all(|s| !s.is_empty()));
+    }
+}
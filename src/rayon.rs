@@ -0,0 +1,175 @@
+//! Parallel iteration with [rayon](https://docs.rs/rayon).
+//!
+//! As there's at least one element, the reductions need neither an
+//! identity nor an `Option`.
+
+use {
+    crate::{NonEmptySlice, NonEmptyVec},
+    rayon::{
+        iter::{IntoParallelIterator, ParallelIterator},
+        slice, vec,
+    },
+};
+
+fn unwrap_non_empty<T>(value: Option<T>) -> T {
+    match value {
+        Some(value) => value,
+        None => unreachable!(),
+    }
+}
+
+impl<T: Send> IntoParallelIterator for NonEmptyVec<T> {
+    type Item = T;
+    type Iter = vec::IntoIter<T>;
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.vec.into_par_iter()
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for &'a NonEmptyVec<T> {
+    type Item = &'a T;
+    type Iter = slice::Iter<'a, T>;
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.vec.as_slice().into_par_iter()
+    }
+}
+
+impl<'a, T: Send> IntoParallelIterator for &'a mut NonEmptyVec<T> {
+    type Item = &'a mut T;
+    type Iter = slice::IterMut<'a, T>;
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.vec.as_mut_slice().into_par_iter()
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for NonEmptySlice<'a, T> {
+    type Item = &'a T;
+    type Iter = slice::Iter<'a, T>;
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.slice.into_par_iter()
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for &NonEmptySlice<'a, T> {
+    type Item = &'a T;
+    type Iter = slice::Iter<'a, T>;
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.slice.into_par_iter()
+    }
+}
+
+impl<'a, T: Sync> NonEmptySlice<'a, T> {
+    /// Return the greatest element, computed in parallel
+    #[inline]
+    pub fn par_max(&self) -> &'a T
+    where
+        T: Ord,
+    {
+        unwrap_non_empty(self.slice.into_par_iter().max())
+    }
+
+    /// Return the smallest element, computed in parallel
+    #[inline]
+    pub fn par_min(&self) -> &'a T
+    where
+        T: Ord,
+    {
+        unwrap_non_empty(self.slice.into_par_iter().min())
+    }
+
+    /// Reduce the elements with `f`, in parallel.
+    ///
+    /// `f` should be associative, as the grouping of operations isn't
+    /// specified.
+    #[inline]
+    pub fn par_reduce<F>(&self, f: F) -> T
+    where
+        T: Clone + Send,
+        F: Fn(T, T) -> T + Sync + Send,
+    {
+        unwrap_non_empty(self.slice.into_par_iter().cloned().reduce_with(f))
+    }
+}
+
+impl<T: Sync> NonEmptyVec<T> {
+    /// Return the greatest element, computed in parallel
+    #[inline]
+    pub fn par_max(&self) -> &T
+    where
+        T: Ord,
+    {
+        unwrap_non_empty(self.vec.as_slice().into_par_iter().max())
+    }
+
+    /// Return the smallest element, computed in parallel
+    #[inline]
+    pub fn par_min(&self) -> &T
+    where
+        T: Ord,
+    {
+        unwrap_non_empty(self.vec.as_slice().into_par_iter().min())
+    }
+
+    /// Reduce the elements with `f`, in parallel.
+    ///
+    /// `f` should be associative, as the grouping of operations isn't
+    /// specified.
+    #[inline]
+    pub fn par_reduce<F>(&self, f: F) -> T
+    where
+        T: Clone + Send,
+        F: Fn(T, T) -> T + Sync + Send,
+    {
+        unwrap_non_empty(self.vec.as_slice().into_par_iter().cloned().reduce_with(f))
+    }
+}
+
+#[cfg(test)]
+mod rayon_tests {
+
+    use {
+        super::*,
+        rayon::iter::{IntoParallelRefIterator, IntoParallelRefMutIterator},
+        std::convert::TryFrom,
+    };
+
+    fn big_vec() -> NonEmptyVec<u64> {
+        let vec: Vec<u64> = (0..300_000u64).map(|i| (i * 7_919) % 100_003).collect();
+        NonEmptyVec::try_from(vec).unwrap()
+    }
+
+    #[test]
+    fn test_compare_with_sequential() {
+        let vec = big_vec();
+        assert_eq!(vec.par_max(), vec.iter().max().unwrap());
+        assert_eq!(vec.par_min(), vec.iter().min().unwrap());
+        assert_eq!(vec.par_reduce(|a, b| a + b), vec.iter().sum::<u64>());
+        assert_eq!(
+            vec.par_iter().filter(|&&v| v % 3 == 0).count(),
+            vec.iter().filter(|&&v| v % 3 == 0).count(),
+        );
+        let slice = NonEmptySlice::try_from(&vec[1000..2000]).unwrap();
+        assert_eq!(slice.par_max(), slice.iter().max().unwrap());
+        assert_eq!(slice.par_reduce(u64::max), *slice.iter().max().unwrap());
+        assert_eq!(
+            slice.into_par_iter().sum::<u64>(),
+            slice.iter().sum::<u64>()
+        );
+    }
+
+    #[test]
+    fn test_single_and_owned() {
+        let single = NonEmptyVec::from(3);
+        assert_eq!(*single.par_max(), 3);
+        assert_eq!(single.par_reduce(|a, b| a * b), 3);
+        let mut vec = big_vec();
+        vec.par_iter_mut().for_each(|v| *v += 1);
+        let total: u64 = vec.clone().into_par_iter().sum();
+        assert_eq!(total, vec.iter().sum::<u64>());
+    }
+}
@@ -0,0 +1,176 @@
+//! Zero-copy serialization with [rkyv](https://docs.rs/rkyv).
+//!
+//! Validating an [`ArchivedNonEmptyVec`] rejects an empty vec, so that
+//! accessing a corrupted buffer can't give a broken value.
+
+use {
+    crate::{unchecked, ArchivedOneToThree, NonEmptyVec, NotEnoughElementsError},
+    rkyv::{
+        bytecheck::{CheckBytes, Verify},
+        munge::munge,
+        rancor::{Fallible, Source},
+        ser::{Allocator, Writer},
+        validation::ArchiveContext,
+        vec::{ArchivedVec, VecResolver},
+        Archive, Deserialize, DeserializeUnsized, Place, Portable, Serialize,
+    },
+    std::num::NonZeroUsize,
+};
+
+/// The archived counterpart of [`NonEmptyVec`], never empty
+#[derive(Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[rkyv(crate = rkyv)]
+#[repr(transparent)]
+pub struct ArchivedNonEmptyVec<T> {
+    vec: ArchivedVec<T>,
+}
+
+impl<T> ArchivedNonEmptyVec<T> {
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unchecked::non_zero(self.vec.len())
+    }
+
+    #[inline]
+    pub fn first(&self) -> &T {
+        unchecked::first(self.vec.as_slice())
+    }
+
+    #[inline]
+    pub fn last(&self) -> &T {
+        unchecked::last(self.vec.as_slice())
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+}
+
+// only checks the invariant, allowed with no_unsafe
+#[allow(unsafe_code)]
+unsafe impl<T, C> Verify<C> for ArchivedNonEmptyVec<T>
+where
+    C: Fallible + ArchiveContext + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _context: &mut C) -> Result<(), C::Error> {
+        if self.vec.is_empty() {
+            Err(C::Error::new(NotEnoughElementsError))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T: Archive> Archive for NonEmptyVec<T> {
+    type Archived = ArchivedNonEmptyVec<T::Archived>;
+    type Resolver = VecResolver;
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedNonEmptyVec { vec } = out);
+        ArchivedVec::resolve_from_slice(self.as_slice(), resolver, vec);
+    }
+}
+
+impl<T, S> Serialize<S> for NonEmptyVec<T>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self.as_slice(), serializer)
+    }
+}
+
+impl<T, D> Deserialize<NonEmptyVec<T>, D> for ArchivedNonEmptyVec<T::Archived>
+where
+    T: Archive,
+    [T::Archived]: DeserializeUnsized<[T], D>,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<NonEmptyVec<T>, D::Error> {
+        let vec: Vec<T> = self.vec.deserialize(deserializer)?;
+        Ok(NonEmptyVec { vec })
+    }
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl<T: Archive> ArchivedOneToThree<T> {
+    pub fn len(&self) -> usize {
+        match self {
+            Self::One(_) => 1,
+            Self::Two(_, _) => 2,
+            Self::Three(_, _, _) => 3,
+        }
+    }
+
+    pub fn first(&self) -> &T::Archived {
+        match self {
+            Self::One(a) => a,
+            Self::Two(a, _) => a,
+            Self::Three(a, _, _) => a,
+        }
+    }
+}
+
+#[cfg(test)]
+mod rkyv_tests {
+
+    use {
+        super::*,
+        crate::OneToThree,
+        rkyv::{access, deserialize, rancor::Error, to_bytes},
+        std::convert::TryFrom,
+    };
+
+    #[test]
+    fn test_non_empty_vec_round_trip() {
+        let vec = NonEmptyVec::<u32>::try_from(vec![3, 5, 8]).unwrap();
+        let bytes = to_bytes::<Error>(&vec).unwrap();
+        let archived = access::<ArchivedNonEmptyVec<rkyv::Archived<u32>>, Error>(&bytes).unwrap();
+        assert_eq!(archived.first().to_native(), 3);
+        assert_eq!(archived.last().to_native(), 8);
+        assert_eq!(archived.len().get(), 3);
+        let vec: NonEmptyVec<u32> = deserialize::<_, Error>(archived).unwrap();
+        assert_eq!(vec.as_slice(), &[3, 5, 8]);
+    }
+
+    #[test]
+    fn test_empty_vec_is_rejected() {
+        let bytes = to_bytes::<Error>(&Vec::<u32>::new()).unwrap();
+        assert!(access::<ArchivedNonEmptyVec<rkyv::Archived<u32>>, Error>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_corrupted_buffer_is_rejected() {
+        let vec = NonEmptyVec::<u8>::try_from(vec![1, 2]).unwrap();
+        let mut bytes = to_bytes::<Error>(&vec).unwrap();
+        // zero the length, which is the last field of the root
+        let len = bytes.len();
+        for b in &mut bytes[len - 4..] {
+            *b = 0;
+        }
+        assert!(access::<ArchivedNonEmptyVec<u8>, Error>(&bytes).is_err());
+        // an out of bounds pointer
+        let mut bytes = to_bytes::<Error>(&vec).unwrap();
+        let len = bytes.len();
+        bytes[len - 8] = 0x7f;
+        assert!(access::<ArchivedNonEmptyVec<u8>, Error>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_one_to_three_round_trip() {
+        let few = OneToThree::two(4u16, 2);
+        let bytes = to_bytes::<Error>(&few).unwrap();
+        let archived = access::<ArchivedOneToThree<u16>, Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), 2);
+        assert_eq!(archived.first().to_native(), 4);
+        let back: OneToThree<u16> = deserialize::<_, Error>(archived).unwrap();
+        assert_eq!(back, few);
+        let mut bytes = to_bytes::<Error>(&few).unwrap();
+        bytes[0] = 7; // invalid variant tag
+        assert!(access::<ArchivedOneToThree<u16>, Error>(&bytes).is_err());
+    }
+}
@@ -0,0 +1,231 @@
+//! Implementations of [`schemars::JsonSchema`], expressing the bounds
+//! (e.g. `minItems: 1` for a [`NonEmptyVec`]).
+//!
+//! The schemas are inlined, as are the ones of `Vec` and `String`.
+
+use {
+    crate::{
+        AtMostVec, BoundedInt, FiniteF32, FiniteF64, NonBlankString, NonEmptyPathBuf,
+        NonEmptyString, NonEmptyVec, OneOrTwo, OneToThree, UnitInterval,
+    },
+    schemars::{json_schema, JsonSchema, Schema, SchemaGenerator},
+    std::borrow::Cow,
+};
+
+/// Schema of an array of `T` with a bounded number of items
+fn array_schema<T: JsonSchema>(
+    generator: &mut SchemaGenerator,
+    min: usize,
+    max: Option<usize>,
+) -> Schema {
+    let mut schema = json_schema!({
+        "type": "array",
+        "items": generator.subschema_for::<T>(),
+        "minItems": min,
+    });
+    if let Some(max) = max {
+        schema.insert("maxItems".into(), max.into());
+    }
+    schema
+}
+
+impl<T: JsonSchema> JsonSchema for NonEmptyVec<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+    fn schema_name() -> Cow<'static, str> {
+        format!("NonEmptyArray_of_{}", T::schema_name()).into()
+    }
+    fn schema_id() -> Cow<'static, str> {
+        format!("strict::NonEmptyVec<{}>", T::schema_id()).into()
+    }
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        array_schema::<T>(generator, 1, None)
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for OneToThree<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+    fn schema_name() -> Cow<'static, str> {
+        format!("OneToThree_of_{}", T::schema_name()).into()
+    }
+    fn schema_id() -> Cow<'static, str> {
+        format!("strict::OneToThree<{}>", T::schema_id()).into()
+    }
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        array_schema::<T>(generator, 1, Some(3))
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for OneOrTwo<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+    fn schema_name() -> Cow<'static, str> {
+        format!("OneOrTwo_of_{}", T::schema_name()).into()
+    }
+    fn schema_id() -> Cow<'static, str> {
+        format!("strict::OneOrTwo<{}>", T::schema_id()).into()
+    }
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        array_schema::<T>(generator, 1, Some(2))
+    }
+}
+
+impl<T: JsonSchema, const MAX: usize> JsonSchema for AtMostVec<T, MAX> {
+    fn inline_schema() -> bool {
+        true
+    }
+    fn schema_name() -> Cow<'static, str> {
+        format!("AtMost_{}_of_{}", MAX, T::schema_name()).into()
+    }
+    fn schema_id() -> Cow<'static, str> {
+        format!("strict::AtMostVec<{}, {}>", T::schema_id(), MAX).into()
+    }
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        array_schema::<T>(generator, 0, Some(MAX))
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> JsonSchema for BoundedInt<MIN, MAX> {
+    fn inline_schema() -> bool {
+        true
+    }
+    fn schema_name() -> Cow<'static, str> {
+        format!("BoundedInt_{}_to_{}", MIN, MAX).into()
+    }
+    fn schema_id() -> Cow<'static, str> {
+        format!("strict::BoundedInt<{}, {}>", MIN, MAX).into()
+    }
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "format": "int64",
+            "minimum": MIN,
+            "maximum": MAX,
+        })
+    }
+}
+
+macro_rules! impl_json_schema {
+    ($name:ident, $id:literal, $schema:tt) => {
+        impl JsonSchema for $name {
+            fn inline_schema() -> bool {
+                true
+            }
+            fn schema_name() -> Cow<'static, str> {
+                stringify!($name).into()
+            }
+            fn schema_id() -> Cow<'static, str> {
+                $id.into()
+            }
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                json_schema!($schema)
+            }
+        }
+    };
+}
+
+impl_json_schema!(FiniteF32, "strict::FiniteF32", {
+    "type": "number",
+    "format": "float",
+});
+impl_json_schema!(FiniteF64, "strict::FiniteF64", {
+    "type": "number",
+    "format": "double",
+});
+impl_json_schema!(UnitInterval, "strict::UnitInterval", {
+    "type": "number",
+    "format": "double",
+    "minimum": 0.0,
+    "maximum": 1.0,
+});
+impl_json_schema!(NonEmptyString, "strict::NonEmptyString", {
+    "type": "string",
+    "minLength": 1,
+});
+impl_json_schema!(NonBlankString, "strict::NonBlankString", {
+    "type": "string",
+    "pattern": "\\S",
+});
+impl_json_schema!(NonEmptyPathBuf, "strict::NonEmptyPathBuf", {
+    "type": "string",
+    "minLength": 1,
+});
+
+#[cfg(test)]
+mod schemars_tests {
+
+    use {
+        super::*,
+        schemars::{schema_for, SchemaGenerator},
+        serde_json::{json, Value},
+    };
+
+    fn inline_schema<T: JsonSchema>() -> Value {
+        SchemaGenerator::default().subschema_for::<T>().to_value()
+    }
+
+    #[test]
+    fn test_collections() {
+        assert_eq!(
+            inline_schema::<NonEmptyVec<u8>>(),
+            json!({
+                "type": "array",
+                "items": { "type": "integer", "format": "uint8", "minimum": 0, "maximum": 255 },
+                "minItems": 1,
+            }),
+        );
+        assert_eq!(
+            inline_schema::<OneToThree<String>>(),
+            json!({
+                "type": "array",
+                "items": { "type": "string" },
+                "minItems": 1,
+                "maxItems": 3,
+            }),
+        );
+        assert_eq!(
+            inline_schema::<AtMostVec<bool, 4>>(),
+            json!({
+                "type": "array",
+                "items": { "type": "boolean" },
+                "minItems": 0,
+                "maxItems": 4,
+            }),
+        );
+    }
+
+    #[test]
+    fn test_values() {
+        assert_eq!(
+            inline_schema::<BoundedInt<1, 12>>(),
+            json!({ "type": "integer", "format": "int64", "minimum": 1, "maximum": 12 }),
+        );
+        assert_eq!(
+            inline_schema::<NonEmptyString>(),
+            json!({ "type": "string", "minLength": 1 }),
+        );
+        assert_eq!(
+            inline_schema::<UnitInterval>(),
+            json!({ "type": "number", "format": "double", "minimum": 0.0, "maximum": 1.0 }),
+        );
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(
+            NonEmptyVec::<NonEmptyString>::schema_name(),
+            "NonEmptyArray_of_NonEmptyString"
+        );
+        assert_eq!(
+            OneToThree::<NonEmptyVec<u8>>::schema_id(),
+            "strict::OneToThree<strict::NonEmptyVec<uint8>>"
+        );
+        let root = schema_for!(NonEmptyVec<FiniteF64>).to_value();
+        assert_eq!(root["title"], "NonEmptyArray_of_FiniteF64");
+        assert_eq!(root["items"]["type"], "number");
+    }
+}
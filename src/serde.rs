@@ -0,0 +1,534 @@
+//! Serde implementations for the crate's types, and helper modules
+//! validating plain std containers, to use with `#[serde(with = "...")]`:
+//!
+//! ```
+//! #[derive(serde::Deserialize)]
+//! struct Config {
+//!     #[serde(with = "strict::serde::non_empty_vec")]
+//!     hosts: Vec<String>,
+//! }
+//! assert!(serde_json::from_str::<Config>(r#"{"hosts":[]}"#).is_err());
+//! ```
+
+use {
+    crate::{
+        AtMostVec, BoundedInt, FiniteF32, FiniteF64, NonBlankString, NonEmptyPathBuf,
+        NonEmptySlice, NonEmptyStr, NonEmptyString, NonEmptyVec, OneToThree, UnitInterval,
+    },
+    serde::{
+        de::{self, IntoDeserializer},
+        Deserialize, Deserializer, Serialize, Serializer,
+    },
+    std::{convert::TryFrom, fmt, marker::PhantomData, path::PathBuf},
+};
+
+impl<const MIN: i64, const MAX: i64> Serialize for BoundedInt<MIN, MAX> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.get())
+    }
+}
+
+impl<'de, const MIN: i64, const MAX: i64> Deserialize<'de> for BoundedInt<MIN, MAX> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = i64::deserialize(deserializer)?;
+        Self::try_new(value).map_err(|_| {
+            de::Error::custom(format_args!(
+                "{} is out of the {}..={} range",
+                value, MIN, MAX
+            ))
+        })
+    }
+}
+
+macro_rules! impl_finite_float {
+    ($name:ident, $f:ty, $serialize:ident) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.$serialize(self.get())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <$f>::deserialize(deserializer)?;
+                Self::try_new(value)
+                    .map_err(|_| de::Error::custom(format_args!("{} isn't a finite number", value)))
+            }
+        }
+    };
+}
+
+impl_finite_float!(FiniteF32, f32, serialize_f32);
+impl_finite_float!(FiniteF64, f64, serialize_f64);
+
+impl Serialize for UnitInterval {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.get())
+    }
+}
+
+impl<'de> Deserialize<'de> for UnitInterval {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = f64::deserialize(deserializer)?;
+        Self::try_new(value)
+            .map_err(|_| de::Error::custom(format_args!("{} isn't in the 0..=1 range", value)))
+    }
+}
+
+impl Serialize for NonEmptyString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for NonEmptyString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        Self::try_from(string).map_err(|_| de::Error::invalid_length(0, &"a non empty string"))
+    }
+}
+
+impl Serialize for NonEmptyStr<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Borrows from the input, so fails with formats which can't lend strings
+/// (e.g. when the JSON string contains escape sequences)
+impl<'de: 'a, 'a> Deserialize<'de> for NonEmptyStr<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let str = <&'de str>::deserialize(deserializer)?;
+        Self::try_from(str).map_err(|_| de::Error::invalid_length(0, &"a non empty string"))
+    }
+}
+
+impl Serialize for NonEmptySlice<'_, u8> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.as_slice())
+    }
+}
+
+/// Borrows from the input, so fails with formats which can't lend bytes
+impl<'de: 'a, 'a> Deserialize<'de> for NonEmptySlice<'a, u8> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = <&'de [u8]>::deserialize(deserializer)?;
+        Self::try_from(bytes).map_err(|_| de::Error::invalid_length(0, &"non empty bytes"))
+    }
+}
+
+impl Serialize for NonBlankString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for NonBlankString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        Self::try_from(string).map_err(|_| de::Error::custom("expected a non blank string"))
+    }
+}
+
+impl Serialize for NonEmptyPathBuf {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_path().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NonEmptyPathBuf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let path = PathBuf::deserialize(deserializer)?;
+        Self::try_from(path).map_err(|_| de::Error::invalid_length(0, &"a non empty path"))
+    }
+}
+
+impl<T: Serialize, const MAX: usize> Serialize for AtMostVec<T, MAX> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+struct AtMostVecVisitor<T, const MAX: usize> {
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>, const MAX: usize> de::Visitor<'de> for AtMostVecVisitor<T, MAX> {
+    type Value = AtMostVec<T, MAX>;
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of at most {} elements", MAX)
+    }
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut vec = AtMostVec::new();
+        // we stop as soon as there's one too many, without reading the rest
+        while let Some(value) = seq.next_element()? {
+            if vec.try_push(value).is_err() {
+                return Err(de::Error::invalid_length(MAX + 1, &self));
+            }
+        }
+        Ok(vec)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const MAX: usize> Deserialize<'de> for AtMostVec<T, MAX> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(AtMostVecVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<T: Serialize> Serialize for OneToThree<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+struct OneToThreeVisitor<T> {
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> de::Visitor<'de> for OneToThreeVisitor<T> {
+    type Value = OneToThree<T>;
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of 1 to 3 elements")
+    }
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let few = match (
+            seq.next_element()?,
+            seq.next_element()?,
+            seq.next_element()?,
+        ) {
+            (Some(a), None, _) => return Ok(OneToThree::One(a)),
+            (Some(a), Some(b), None) => return Ok(OneToThree::Two(a, b)),
+            (Some(a), Some(b), Some(c)) => OneToThree::Three(a, b, c),
+            _ => {
+                return Err(de::Error::custom(
+                    "expected between 1 and 3 elements, got 0",
+                ))
+            }
+        };
+        // the extra elements are counted, for the error message
+        let mut len = 3;
+        while seq.next_element::<de::IgnoredAny>()?.is_some() {
+            len += 1;
+        }
+        if len > 3 {
+            return Err(de::Error::custom(format_args!(
+                "expected between 1 and 3 elements, got {}",
+                len
+            )));
+        }
+        Ok(few)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for OneToThree<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(OneToThreeVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Validate that a `Vec<T>` has at least one element
+pub mod non_empty_vec {
+    use super::*;
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        vec: &[T],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        vec.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<T>, D::Error> {
+        let vec = Vec::<T>::deserialize(deserializer)?;
+        if vec.is_empty() {
+            return Err(de::Error::invalid_length(0, &"a non empty sequence"));
+        }
+        Ok(vec)
+    }
+}
+
+/// Validate that a `String` isn't empty
+pub mod non_empty_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(string: &str, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(string)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        if string.is_empty() {
+            return Err(de::Error::invalid_length(0, &"a non empty string"));
+        }
+        Ok(string)
+    }
+}
+
+/// Validate that a `Vec<T>` has 1 to 3 elements
+pub mod one_to_three_len {
+    use super::*;
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        vec: &[T],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        vec.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<T>, D::Error> {
+        let vec = Vec::<T>::deserialize(deserializer)?;
+        if !(1..=3).contains(&vec.len()) {
+            return Err(de::Error::invalid_length(vec.len(), &"1 to 3 elements"));
+        }
+        Ok(vec)
+    }
+}
+
+/// Deserialize a `NonEmptyVec<T>` from either a non empty sequence or a
+/// single scalar or map value, which makes a one element vec.
+///
+/// Serialization always writes a sequence.
+pub mod nev_scalar_or_seq {
+    use super::*;
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        vec: &NonEmptyVec<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        vec.as_slice().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NonEmptyVec<T>, D::Error> {
+        deserializer.deserialize_any(ScalarOrSeqVisitor {
+            marker: PhantomData,
+        })
+    }
+
+    struct ScalarOrSeqVisitor<T> {
+        marker: PhantomData<T>,
+    }
+
+    macro_rules! visit_scalar {
+        ($visit:ident, $t:ty) => {
+            fn $visit<E: de::Error>(self, v: $t) -> Result<Self::Value, E> {
+                T::deserialize(v.into_deserializer()).map(NonEmptyVec::from)
+            }
+        };
+    }
+
+    impl<'de, T: Deserialize<'de>> de::Visitor<'de> for ScalarOrSeqVisitor<T> {
+        type Value = NonEmptyVec<T>;
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a value or a non empty sequence")
+        }
+        visit_scalar!(visit_bool, bool);
+        visit_scalar!(visit_i64, i64);
+        visit_scalar!(visit_u64, u64);
+        visit_scalar!(visit_f64, f64);
+        visit_scalar!(visit_char, char);
+        visit_scalar!(visit_string, String);
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            T::deserialize(v.into_deserializer()).map(NonEmptyVec::from)
+        }
+        fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+            T::deserialize(de::value::BorrowedStrDeserializer::new(v)).map(NonEmptyVec::from)
+        }
+        fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            T::deserialize(de::value::MapAccessDeserializer::new(map)).map(NonEmptyVec::from)
+        }
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut vec = match seq.next_element::<T>()? {
+                Some(first) => NonEmptyVec::from(first),
+                None => return Err(de::Error::invalid_length(0, &"a non empty sequence")),
+            };
+            while let Some(value) = seq.next_element()? {
+                vec.push(value);
+            }
+            Ok(vec)
+        }
+    }
+}
+
+#[cfg(test)]
+mod serde_tests {
+
+    use crate::*;
+
+    #[test]
+    fn test_bounded_int() {
+        type Port = BoundedInt<1, 65535>;
+        let port: Port = serde_json::from_str("8080").unwrap();
+        assert_eq!(port.get(), 8080);
+        assert_eq!(serde_json::to_string(&port).unwrap(), "8080");
+        let err = serde_json::from_str::<Port>("0").unwrap_err();
+        assert!(err.to_string().contains("0 is out of the 1..=65535 range"));
+        assert!(serde_json::from_str::<Port>("70000").is_err());
+    }
+
+    #[test]
+    fn test_finite_float() {
+        let v: FiniteF64 = serde_json::from_str("-2.5").unwrap();
+        assert_eq!(v.get(), -2.5);
+        assert_eq!(serde_json::to_string(&v).unwrap(), "-2.5");
+        let v: FiniteF32 = serde_json::from_str("1e3").unwrap();
+        assert_eq!(v.get(), 1000.0);
+        // f32 overflow
+        assert!(serde_json::from_str::<FiniteF32>("1e300").is_err());
+    }
+
+    #[test]
+    fn test_unit_interval() {
+        let v: UnitInterval = serde_json::from_str("0.75").unwrap();
+        assert_eq!(v.get(), 0.75);
+        assert_eq!(serde_json::to_string(&v).unwrap(), "0.75");
+        assert!(serde_json::from_str::<UnitInterval>("1.5").is_err());
+        assert!(serde_json::from_str::<UnitInterval>("-1").is_err());
+    }
+
+    #[test]
+    fn test_strings() {
+        let s: NonEmptyString = serde_json::from_str(r#"" ""#).unwrap();
+        assert_eq!(serde_json::to_string(&s).unwrap(), r#"" ""#);
+        assert!(serde_json::from_str::<NonEmptyString>(r#""""#).is_err());
+        let s: NonBlankString = serde_json::from_str(r#"" a ""#).unwrap();
+        assert_eq!(s.trimmed(), "a");
+        assert_eq!(serde_json::to_string(&s).unwrap(), r#"" a ""#);
+        assert!(serde_json::from_str::<NonBlankString>(r#"" ""#).is_err());
+    }
+
+    #[test]
+    fn test_at_most_vec() {
+        let v: AtMostVec<u8, 3> = serde_json::from_str("[1, 2]").unwrap();
+        assert_eq!(v.as_slice(), &[1, 2]);
+        assert_eq!(serde_json::to_string(&v).unwrap(), "[1,2]");
+        let v: AtMostVec<u8, 3> = serde_json::from_str("[]").unwrap();
+        assert!(v.is_empty());
+        assert!(serde_json::from_str::<AtMostVec<u8, 3>>("[1, 2, 3, 4]").is_err());
+    }
+
+    #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    struct Binding {
+        keys: OneToThree<String>,
+        action: String,
+    }
+
+    #[test]
+    fn test_one_to_three() {
+        for json in ["[1]", "[1,2]", "[1,2,3]"] {
+            let few: OneToThree<u8> = serde_json::from_str(json).unwrap();
+            assert_eq!(few.len(), usize::from(*few.last()));
+            assert_eq!(serde_json::to_string(&few).unwrap(), json);
+        }
+        let err = serde_json::from_str::<OneToThree<u8>>("[]").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected between 1 and 3 elements, got 0"));
+        let err = serde_json::from_str::<OneToThree<u8>>("[1,2,3,4,5]").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected between 1 and 3 elements, got 5"));
+        let json = r#"{"keys":["ctrl","t"],"action":"new_tab"}"#;
+        let binding: Binding = serde_json::from_str(json).unwrap();
+        assert_eq!(binding.keys, OneToThree::two("ctrl", "t").map(String::from));
+        assert_eq!(serde_json::to_string(&binding).unwrap(), json);
+    }
+
+    #[test]
+    fn test_borrowed() {
+        let input = String::from(r#"["name","abc"]"#);
+        let (name, data): (NonEmptyStr, NonEmptySlice<u8>) = serde_json::from_str(&input).unwrap();
+        assert_eq!(name, "name");
+        assert_eq!(data.as_slice(), b"abc");
+        // the values point into the input
+        let range = input.as_bytes().as_ptr_range();
+        assert!(range.contains(&data.as_slice().as_ptr()));
+        assert!(range.contains(&name.as_str().as_ptr()));
+        assert_eq!(serde_json::to_string(&name).unwrap(), r#""name""#);
+        assert_eq!(serde_json::to_string(&data).unwrap(), "[97,98,99]");
+        assert!(serde_json::from_str::<NonEmptyStr>(r#""""#).is_err());
+        assert!(serde_json::from_str::<NonEmptySlice<u8>>(r#""""#).is_err());
+        // an escaped string can't be borrowed
+        assert!(serde_json::from_str::<NonEmptyStr>(r#""a\nb""#).is_err());
+    }
+
+    #[derive(Debug, ::serde::Serialize, ::serde::Deserialize)]
+    struct Legacy {
+        #[serde(with = "crate::serde::non_empty_vec")]
+        hosts: Vec<String>,
+        #[serde(with = "crate::serde::non_empty_string")]
+        name: String,
+        #[serde(with = "crate::serde::one_to_three_len")]
+        ports: Vec<u16>,
+    }
+
+    #[test]
+    fn test_with_helpers() {
+        let json = r#"{"hosts":["a","b"],"name":"x","ports":[80,443]}"#;
+        let legacy: Legacy = serde_json::from_str(json).unwrap();
+        assert_eq!(legacy.hosts, vec!["a", "b"]);
+        assert_eq!(legacy.ports, vec![80, 443]);
+        assert_eq!(serde_json::to_string(&legacy).unwrap(), json);
+        let err =
+            serde_json::from_str::<Legacy>(r#"{"hosts":[],"name":"x","ports":[1]}"#).unwrap_err();
+        assert!(err.to_string().contains("a non empty sequence"));
+        let err =
+            serde_json::from_str::<Legacy>(r#"{"hosts":["a"],"name":"","ports":[1]}"#).unwrap_err();
+        assert!(err.to_string().contains("a non empty string"));
+        let err = serde_json::from_str::<Legacy>(r#"{"hosts":["a"],"name":"x","ports":[1,2,3,4]}"#)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("invalid length 4, expected 1 to 3 elements"));
+        assert!(
+            serde_json::from_str::<Legacy>(r#"{"hosts":["a"],"name":"x","ports":[]}"#).is_err()
+        );
+    }
+
+    #[derive(Debug, ::serde::Serialize, ::serde::Deserialize)]
+    struct Targets {
+        #[serde(with = "crate::serde::nev_scalar_or_seq")]
+        hosts: NonEmptyVec<String>,
+    }
+
+    #[test]
+    fn test_scalar_or_seq() {
+        let parse =
+            |json| serde_json::from_str::<Targets>(json).map(|t| t.hosts.as_slice().to_vec());
+        assert_eq!(parse(r#"{"hosts":"x"}"#).unwrap(), vec!["x"]);
+        assert_eq!(parse(r#"{"hosts":["x"]}"#).unwrap(), vec!["x"]);
+        assert_eq!(parse(r#"{"hosts":["x","y"]}"#).unwrap(), vec!["x", "y"]);
+        let err = parse(r#"{"hosts":[]}"#).unwrap_err();
+        assert!(err.to_string().contains("a non empty sequence"));
+        assert!(parse(r#"{"hosts":3}"#).is_err());
+        let targets: Targets = serde_json::from_str(r#"{"hosts":"x"}"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&targets).unwrap(),
+            r#"{"hosts":["x"]}"#
+        );
+        let values: Vec<serde_json::Value> = serde_json::from_str("[1, [2, 3]]").unwrap();
+        let vecs: Vec<NonEmptyVec<u8>> = values
+            .into_iter()
+            .map(|value| crate::serde::nev_scalar_or_seq::deserialize(value).unwrap())
+            .collect();
+        assert_eq!(vecs[0].as_slice(), &[1]);
+        assert_eq!(vecs[1].as_slice(), &[2, 3]);
+    }
+
+    #[test]
+    fn test_path() {
+        let p: NonEmptyPathBuf = serde_json::from_str(r#""/tmp/a""#).unwrap();
+        assert_eq!(p.file_name_or_root(), "a");
+        assert_eq!(serde_json::to_string(&p).unwrap(), r#""/tmp/a""#);
+        assert!(serde_json::from_str::<NonEmptyPathBuf>(r#""""#).is_err());
+    }
+}
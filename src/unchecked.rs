@@ -0,0 +1,84 @@
+//! Accessors relying on the non emptiness of the collections.
+//!
+//! By default they skip the bounds checks. With the `no_unsafe` feature,
+//! they're implemented with safe code, with the same behavior.
+
+use std::{num::NonZeroUsize, ops::Range};
+
+#[cfg(not(feature = "no_unsafe"))]
+mod imp {
+    use super::*;
+
+    #[inline]
+    pub(crate) const fn non_zero(len: usize) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(len) }
+    }
+
+    #[inline]
+    pub(crate) fn get<T>(slice: &[T], idx: usize) -> &T {
+        unsafe { slice.get_unchecked(idx) }
+    }
+
+    #[inline]
+    pub(crate) fn get_mut<T>(slice: &mut [T], idx: usize) -> &mut T {
+        unsafe { slice.get_unchecked_mut(idx) }
+    }
+
+    #[inline]
+    pub(crate) fn get_range<T>(slice: &[T], range: Range<usize>) -> &[T] {
+        unsafe { slice.get_unchecked(range) }
+    }
+}
+
+#[cfg(feature = "no_unsafe")]
+mod imp {
+    use super::*;
+
+    #[inline]
+    pub(crate) const fn non_zero(len: usize) -> NonZeroUsize {
+        match NonZeroUsize::new(len) {
+            Some(len) => len,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get<T>(slice: &[T], idx: usize) -> &T {
+        &slice[idx]
+    }
+
+    #[inline]
+    pub(crate) fn get_mut<T>(slice: &mut [T], idx: usize) -> &mut T {
+        &mut slice[idx]
+    }
+
+    #[inline]
+    pub(crate) fn get_range<T>(slice: &[T], range: Range<usize>) -> &[T] {
+        &slice[range]
+    }
+}
+
+pub(crate) use imp::*;
+
+/// The first element of a slice known to be non empty
+#[inline]
+pub(crate) fn first<T>(slice: &[T]) -> &T {
+    get(slice, 0)
+}
+
+#[inline]
+pub(crate) fn first_mut<T>(slice: &mut [T]) -> &mut T {
+    get_mut(slice, 0)
+}
+
+/// The last element of a slice known to be non empty
+#[inline]
+pub(crate) fn last<T>(slice: &[T]) -> &T {
+    get(slice, slice.len() - 1)
+}
+
+#[inline]
+pub(crate) fn last_mut<T>(slice: &mut [T]) -> &mut T {
+    let idx = slice.len() - 1;
+    get_mut(slice, idx)
+}
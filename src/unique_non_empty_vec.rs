@@ -0,0 +1,373 @@
+use {
+    crate::{unchecked, NonEmpty, NonEmptyVec, NotEnoughElementsError},
+    std::{
+        collections::{hash_map::RandomState, HashMap},
+        convert::TryFrom,
+        fmt,
+        hash::{BuildHasher, Hash, Hasher},
+        num::NonZeroUsize,
+        ops::Deref,
+        slice,
+    },
+};
+
+/// Error returned when building a [`UniqueNonEmptyVec`] from a sequence
+/// which is empty or contains duplicates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UniqueVecError {
+    Empty,
+    /// The element at this index is a duplicate of a previous one
+    Duplicate(usize),
+}
+
+impl fmt::Display for UniqueVecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no element"),
+            Self::Duplicate(idx) => write!(f, "element at index {} is a duplicate", idx),
+        }
+    }
+}
+
+impl std::error::Error for UniqueVecError {}
+
+/// A non empty vec with no duplicate element, preserving insertion order.
+///
+/// An internal index, which doesn't need `T: Clone`, makes `contains`
+/// and `position` O(1).
+#[derive(Clone)]
+pub struct UniqueNonEmptyVec<T: Eq + Hash> {
+    vec: Vec<T>,
+    /// positions of the elements in vec, by hash
+    index: HashMap<u64, Vec<usize>>,
+    hasher: RandomState,
+}
+
+impl<T: Eq + Hash> UniqueNonEmptyVec<T> {
+    pub fn new(first: T) -> Self {
+        let mut unique = Self {
+            vec: Vec::new(),
+            index: HashMap::new(),
+            hasher: RandomState::new(),
+        };
+        unique.push_unchecked(first);
+        unique
+    }
+
+    /// Build from a vec, failing if it's empty or has duplicates
+    pub fn try_from_strict(vec: Vec<T>) -> Result<Self, UniqueVecError> {
+        let mut iter = vec.into_iter();
+        let mut unique = match iter.next() {
+            Some(first) => Self::new(first),
+            None => return Err(UniqueVecError::Empty),
+        };
+        for value in iter {
+            let idx = unique.vec.len();
+            if unique.try_push(value).is_err() {
+                return Err(UniqueVecError::Duplicate(idx));
+            }
+        }
+        Ok(unique)
+    }
+
+    /// Build from a vec, dropping the duplicates (the first occurrence of
+    /// every element is kept). Fail only if the vec is empty.
+    pub fn from_vec_dedup(vec: Vec<T>) -> Result<Self, NotEnoughElementsError> {
+        let mut iter = vec.into_iter();
+        let mut unique = match iter.next() {
+            Some(first) => Self::new(first),
+            None => return Err(NotEnoughElementsError),
+        };
+        for value in iter {
+            let _ = unique.try_push(value);
+        }
+        Ok(unique)
+    }
+
+    /// Build from a non empty vec, dropping the duplicates (the first
+    /// occurrence of every element is kept)
+    pub fn from_non_empty_vec_dedup(vec: NonEmptyVec<T>) -> Self {
+        match Self::from_vec_dedup(vec.vec) {
+            Ok(unique) => unique,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    fn hash_of(&self, value: &T) -> u64 {
+        let mut hasher = self.hasher.build_hasher();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn push_unchecked(&mut self, value: T) {
+        let hash = self.hash_of(&value);
+        self.index.entry(hash).or_default().push(self.vec.len());
+        self.vec.push(value);
+    }
+
+    /// Remove the position of a removed value from the index
+    fn unindex(&mut self, value: &T, idx: usize) {
+        let hash = self.hash_of(value);
+        if let Some(positions) = self.index.get_mut(&hash) {
+            positions.retain(|&i| i != idx);
+            if positions.is_empty() {
+                self.index.remove(&hash);
+            }
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unchecked::non_zero(self.vec.len())
+    }
+
+    #[inline]
+    pub fn has_len(&self, len: usize) -> bool {
+        self.vec.len() == len
+    }
+
+    #[inline]
+    pub fn first(&self) -> &T {
+        unchecked::first(&self.vec)
+    }
+
+    #[inline]
+    pub fn last(&self) -> &T {
+        unchecked::last(&self.vec)
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+
+    /// Return the index of the element, in O(1)
+    pub fn position(&self, value: &T) -> Option<usize> {
+        self.index
+            .get(&self.hash_of(value))
+            .and_then(|positions| positions.iter().copied().find(|&i| self.vec[i] == *value))
+    }
+
+    /// Tell whether the element is present, in O(1)
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        self.position(value).is_some()
+    }
+
+    /// Add the value at the end, or give it back if it's already present
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        if self.contains(&value) {
+            Err(value)
+        } else {
+            self.push_unchecked(value);
+            Ok(())
+        }
+    }
+
+    /// Remove the element at the given index, unless it's the only one.
+    ///
+    /// Panics if the index is out of bounds. This is O(n) as the
+    /// following elements are shifted, and their index updated.
+    pub fn remove(&mut self, idx: usize) -> Result<T, NotEnoughElementsError> {
+        let len = self.vec.len();
+        assert!(
+            idx < len,
+            "removal index (is {}) should be < len (is {})",
+            idx,
+            len
+        );
+        if len == 1 {
+            return Err(NotEnoughElementsError);
+        }
+        let value = self.vec.remove(idx);
+        self.unindex(&value, idx);
+        for positions in self.index.values_mut() {
+            for i in positions.iter_mut() {
+                if *i > idx {
+                    *i -= 1;
+                }
+            }
+        }
+        Ok(value)
+    }
+
+    /// Remove the last element and return it, or [`None`] if it's
+    /// the only one
+    pub fn pop(&mut self) -> Option<T> {
+        if self.vec.len() == 1 {
+            return None;
+        }
+        // no other index changes, so this is O(1)
+        let value = self.vec.pop()?;
+        self.unindex(&value, self.vec.len());
+        Some(value)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.vec.iter()
+    }
+
+    #[inline]
+    pub fn into_non_empty_vec(self) -> NonEmptyVec<T> {
+        NonEmptyVec { vec: self.vec }
+    }
+}
+
+impl<T: Eq + Hash> TryFrom<Vec<T>> for UniqueNonEmptyVec<T> {
+    type Error = UniqueVecError;
+    /// Fail if the vec is empty or has duplicates
+    #[inline]
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        Self::try_from_strict(vec)
+    }
+}
+
+impl<T: Eq + Hash> TryFrom<NonEmptyVec<T>> for UniqueNonEmptyVec<T> {
+    type Error = UniqueVecError;
+    /// Fail if the vec has duplicates
+    #[inline]
+    fn try_from(vec: NonEmptyVec<T>) -> Result<Self, Self::Error> {
+        Self::try_from_strict(vec.vec)
+    }
+}
+
+impl<T: Eq + Hash> From<UniqueNonEmptyVec<T>> for NonEmptyVec<T> {
+    #[inline]
+    fn from(unique: UniqueNonEmptyVec<T>) -> Self {
+        unique.into_non_empty_vec()
+    }
+}
+
+impl<T: Eq + Hash> From<T> for UniqueNonEmptyVec<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Eq + Hash> Deref for UniqueNonEmptyVec<T> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<'a, T: Eq + Hash> IntoIterator for &'a UniqueNonEmptyVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.vec.iter()
+    }
+}
+
+impl<T: Eq + Hash + fmt::Debug> fmt::Debug for UniqueNonEmptyVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(&self.vec).finish()
+    }
+}
+
+impl<T: Eq + Hash> PartialEq for UniqueNonEmptyVec<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.vec == other.vec
+    }
+}
+
+impl<T: Eq + Hash> Eq for UniqueNonEmptyVec<T> {}
+
+impl<T: Eq + Hash> crate::non_empty::Sealed for UniqueNonEmptyVec<T> {}
+
+impl<T: Eq + Hash> NonEmpty for UniqueNonEmptyVec<T> {
+    type Item = T;
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        T: 'a;
+    #[inline]
+    fn len(&self) -> NonZeroUsize {
+        self.len()
+    }
+    #[inline]
+    fn first(&self) -> &T {
+        self.first()
+    }
+    #[inline]
+    fn last(&self) -> &T {
+        self.last()
+    }
+    #[inline]
+    fn iter(&self) -> slice::Iter<'_, T> {
+        self.vec.iter()
+    }
+}
+
+#[cfg(test)]
+mod unique_non_empty_vec_tests {
+
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        assert_eq!(
+            UniqueNonEmptyVec::<u8>::try_from(vec![]),
+            Err(UniqueVecError::Empty),
+        );
+        assert_eq!(
+            UniqueNonEmptyVec::<&str>::try_from(vec!["a", "b", "a"]),
+            Err(UniqueVecError::Duplicate(2)),
+        );
+        let tags = UniqueNonEmptyVec::from_vec_dedup(vec!["b", "a", "b", "c", "a"]).unwrap();
+        assert_eq!(tags.as_slice(), &["b", "a", "c"]);
+        assert!(UniqueNonEmptyVec::<u8>::from_vec_dedup(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_push_contains() {
+        let mut tags = UniqueNonEmptyVec::new("rust".to_string());
+        assert_eq!(tags.try_push("go".to_string()), Ok(()));
+        assert_eq!(tags.try_push("rust".to_string()), Err("rust".to_string()));
+        assert!(tags.contains(&"go".to_string()));
+        assert!(!tags.contains(&"c".to_string()));
+        assert_eq!(tags.position(&"go".to_string()), Some(1));
+        assert_eq!(tags.len().get(), 2);
+    }
+
+    #[test]
+    fn test_remove_keeps_index() {
+        let mut v = UniqueNonEmptyVec::try_from((0..10).collect::<Vec<u32>>()).unwrap();
+        assert_eq!(v.remove(3).ok(), Some(3));
+        assert_eq!(v.remove(0).ok(), Some(0));
+        assert_eq!(v.pop(), Some(9));
+        assert_eq!(v.as_slice(), &[1, 2, 4, 5, 6, 7, 8]);
+        for (i, e) in v.as_slice().iter().enumerate() {
+            assert_eq!(v.position(e), Some(i));
+        }
+        assert!(!v.contains(&3));
+        assert_eq!(v.try_push(3), Ok(()));
+        assert_eq!(v.position(&3), Some(7));
+        while v.pop().is_some() {}
+        assert_eq!(v.as_slice(), &[1]);
+        assert!(v.remove(0).is_err());
+        assert_eq!(*v.first(), 1);
+        assert_eq!(*v.last(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index")]
+    fn test_remove_out_of_bounds() {
+        let mut v = UniqueNonEmptyVec::from(1);
+        let _ = v.remove(99);
+    }
+
+    #[test]
+    fn test_non_empty_vec_conversion() {
+        let vec: NonEmptyVec<char> = vec!['x', 'y', 'x'].try_into().unwrap();
+        assert!(UniqueNonEmptyVec::<char>::try_from(vec.clone()).is_err());
+        let unique = UniqueNonEmptyVec::from_non_empty_vec_dedup(vec);
+        let vec: NonEmptyVec<char> = unique.into();
+        assert_eq!(vec.as_slice(), &['x', 'y']);
+    }
+}
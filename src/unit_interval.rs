@@ -0,0 +1,164 @@
+use {
+    crate::{FiniteF64, OutOfRangeError},
+    std::{cmp::Ordering, convert::TryFrom, fmt, hash, ops::Mul},
+};
+
+/// A float in `0.0..=1.0`, e.g. an opacity, a progress or a probability.
+///
+/// As it's never NaN, it's totally ordered and can be hashed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitInterval {
+    value: f64,
+}
+
+impl UnitInterval {
+    pub const ZERO: Self = Self { value: 0.0 };
+    pub const ONE: Self = Self { value: 1.0 };
+
+    #[inline]
+    pub fn try_new(value: f64) -> Result<Self, OutOfRangeError> {
+        if (0.0..=1.0).contains(&value) {
+            // adding 0.0 turns -0.0 into 0.0
+            Ok(Self { value: value + 0.0 })
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    /// Build a value, clamping it in `0.0..=1.0` (NaN gives `ZERO`)
+    #[inline]
+    pub fn new_clamped(value: f64) -> Self {
+        if value >= 1.0 {
+            Self::ONE
+        } else if value > 0.0 {
+            Self { value }
+        } else {
+            Self::ZERO
+        }
+    }
+
+    #[inline]
+    pub const fn get(self) -> f64 {
+        self.value
+    }
+
+    /// Return `1 - self`
+    #[inline]
+    pub fn complement(self) -> Self {
+        Self {
+            value: 1.0 - self.value,
+        }
+    }
+
+    /// Interpolate linearly between `a` (for `ZERO`) and `b` (for `ONE`)
+    #[inline]
+    pub fn lerp(self, a: f64, b: f64) -> f64 {
+        a + (b - a) * self.value
+    }
+}
+
+impl Eq for UnitInterval {}
+
+impl PartialOrd for UnitInterval {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UnitInterval {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.value.partial_cmp(&other.value) {
+            Some(ord) => ord,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl hash::Hash for UnitInterval {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.value.to_bits().hash(state);
+    }
+}
+
+/// The product of two values of the unit interval stays in the interval
+impl Mul for UnitInterval {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            value: self.value * rhs.value,
+        }
+    }
+}
+
+impl TryFrom<f64> for UnitInterval {
+    type Error = OutOfRangeError;
+    #[inline]
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl From<UnitInterval> for f64 {
+    #[inline]
+    fn from(u: UnitInterval) -> Self {
+        u.value
+    }
+}
+
+impl From<UnitInterval> for FiniteF64 {
+    #[inline]
+    fn from(u: UnitInterval) -> Self {
+        match FiniteF64::try_new(u.value) {
+            Ok(f) => f,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Display for UnitInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod unit_interval_tests {
+
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(UnitInterval::try_from(0.25).unwrap().get(), 0.25);
+        assert_eq!(UnitInterval::try_from(1.0), Ok(UnitInterval::ONE));
+        assert_eq!(UnitInterval::try_from(-0.0), Ok(UnitInterval::ZERO));
+        assert!(UnitInterval::try_from(1.01).is_err());
+        assert!(UnitInterval::try_from(-0.1).is_err());
+        assert!(UnitInterval::try_from(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_clamped() {
+        assert_eq!(UnitInterval::new_clamped(3.0), UnitInterval::ONE);
+        assert_eq!(UnitInterval::new_clamped(-3.0), UnitInterval::ZERO);
+        assert_eq!(UnitInterval::new_clamped(f64::NAN), UnitInterval::ZERO);
+        assert_eq!(UnitInterval::new_clamped(0.5).get(), 0.5);
+    }
+
+    #[test]
+    fn test_operations() {
+        let half = UnitInterval::try_new(0.5).unwrap();
+        let quarter = UnitInterval::try_new(0.25).unwrap();
+        assert_eq!(half * half, quarter);
+        assert_eq!(quarter.complement().get(), 0.75);
+        assert_eq!(half.lerp(10.0, 20.0), 15.0);
+        assert_eq!(UnitInterval::ZERO.lerp(10.0, 20.0), 10.0);
+        assert_eq!(UnitInterval::ONE.lerp(10.0, 20.0), 20.0);
+        assert!(quarter < half);
+        assert_eq!(half.max(quarter), half);
+        assert_eq!(half.to_string(), "0.5");
+    }
+}
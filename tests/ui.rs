@@ -0,0 +1,6 @@
+/// Check that the compile time checked macros reject empty literals
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
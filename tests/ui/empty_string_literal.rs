@@ -0,0 +1,3 @@
+fn main() {
+    let _ = strict::non_empty_string!("");
+}